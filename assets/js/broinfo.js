@@ -55,6 +55,36 @@ function get_timezone() {
     return '';
 }
 
+async function get_ua_data() {
+    // navigator.userAgentData: only on Chromium based browsers
+    if (!('userAgentData' in navigator)) {
+        return null;
+    }
+    var d = navigator.userAgentData;
+    var r = {
+        brands: d.brands || [],
+        mobile: d.mobile || false,
+        platform: d.platform || '',
+    };
+    if (d.getHighEntropyValues) {
+        try {
+            var h = await d.getHighEntropyValues([
+                'architecture', 'bitness', 'model',
+                'platformVersion', 'fullVersionList', 'wow64'
+            ]);
+            r.model = h.model || '';
+            r.platform_version = h.platformVersion || '';
+            r.architecture = h.architecture || '';
+            r.bitness = h.bitness || '';
+            r.full_version_list = h.fullVersionList || [];
+            r.wow64 = h.wow64 || false;
+        } catch (e) {
+            // the high entropy values are not permitted
+        }
+    }
+    return r;
+}
+
 const v_oscpu = get_navigator_prop('oscpu', '');
 const v_platform = get_navigator_prop('platform', '');
 const v_cpu_cores = get_navigator_prop('hardwareConcurrency', null);
@@ -62,6 +92,7 @@ const v_user_agent = get_navigator_prop('userAgent', '');
 const v_cookie_enabled = get_navigator_prop('cookieEnabled', false);
 const v_user_language = get_navigator_prop('language', '');
 const v_device_memory = get_navigator_prop('deviceMemory', null);
+const v_max_touch_points = get_navigator_prop('maxTouchPoints', null);
 
 const v_referrer = get_document_prop('referrer', '');
 
@@ -74,6 +105,7 @@ const v_has_local_storage = (typeof localStorage != 'undefined');
 const v_has_session_storage = (typeof sessionStorage != 'undefined');
 const v_is_dark_mode = is_dark_mode();
 const v_timezone = get_timezone();
+const v_ua_data = await get_ua_data();
 
 return {
     basic: {
@@ -97,6 +129,8 @@ return {
         has_session_storage: v_has_session_storage,
         is_dark_mode: v_is_dark_mode,
         timezone: v_timezone,
+        max_touch_points: v_max_touch_points,
     },
+    ua_data: v_ua_data,
 };
 /* vim: set ts=4 sw=4 sts=0 expandtab: ### mode line for vim */
@@ -0,0 +1,279 @@
+//
+// Generates the rust tables from `resources/regexes.yaml` and `resources/bots.yaml`,
+// so that the bundled rules are used without deserializing yaml at runtime.
+// And from `resources/browserslist.yaml`, the snapshot for the `browserslist` queries,
+// and from `resources/os_releases.yaml`, the releases for `Os::release_info()`,
+// and from `resources/device_models.yaml`, the marketing names of the devices,
+// and from `resources/in_apps.yaml`, the tokens of the in-app browsers.
+//
+use serde_yaml::Value;
+use std::fmt::Write;
+
+const REGEXES_YAML: &str = "resources/regexes.yaml";
+const BOTS_YAML: &str = "resources/bots.yaml";
+const BROWSERSLIST_YAML: &str = "resources/browserslist.yaml";
+const OS_RELEASES_YAML: &str = "resources/os_releases.yaml";
+const DEVICE_MODELS_YAML: &str = "resources/device_models.yaml";
+const IN_APPS_YAML: &str = "resources/in_apps.yaml";
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed={REGEXES_YAML}");
+    println!("cargo:rerun-if-changed={BOTS_YAML}");
+    println!("cargo:rerun-if-changed={BROWSERSLIST_YAML}");
+    println!("cargo:rerun-if-changed={OS_RELEASES_YAML}");
+    println!("cargo:rerun-if-changed={DEVICE_MODELS_YAML}");
+    println!("cargo:rerun-if-changed={IN_APPS_YAML}");
+    //
+    let regexes = load(REGEXES_YAML);
+    let bots = load(BOTS_YAML);
+    let mut out = String::new();
+    out += "// @generated by build.rs. Do not edit.\n\n";
+    gen_table(
+        &mut out,
+        "USER_AGENT_PARSERS",
+        &regexes,
+        "user_agent_parsers",
+        &[
+            "family_replacement",
+            "v1_replacement",
+            "v2_replacement",
+            "v3_replacement",
+            "v4_replacement",
+        ],
+    );
+    gen_table(
+        &mut out,
+        "OS_PARSERS",
+        &regexes,
+        "os_parsers",
+        &[
+            "os_replacement",
+            "os_v1_replacement",
+            "os_v2_replacement",
+            "os_v3_replacement",
+            "os_v4_replacement",
+        ],
+    );
+    gen_table(
+        &mut out,
+        "DEVICE_PARSERS",
+        &regexes,
+        "device_parsers",
+        &[
+            "regex_flag",
+            "device_replacement",
+            "brand_replacement",
+            "model_replacement",
+        ],
+    );
+    check_kinds(&bots);
+    gen_table(
+        &mut out,
+        "CLIENT_PARSERS",
+        &bots,
+        "client_parsers",
+        &["kind", "name", "vendor"],
+    );
+    let out_dir = std::path::PathBuf::from(std::env::var("OUT_DIR").unwrap());
+    std::fs::write(out_dir.join("bundled.rs"), out).unwrap();
+    //
+    let snapshot = load(BROWSERSLIST_YAML);
+    std::fs::write(out_dir.join("browserslist.rs"), gen_agents(&snapshot)).unwrap();
+    //
+    let releases = load(OS_RELEASES_YAML);
+    std::fs::write(out_dir.join("os_releases.rs"), gen_releases(&releases)).unwrap();
+    //
+    let models = load(DEVICE_MODELS_YAML);
+    std::fs::write(out_dir.join("device_models.rs"), gen_models(&models)).unwrap();
+    //
+    let in_apps = load(IN_APPS_YAML);
+    std::fs::write(out_dir.join("in_apps.rs"), gen_in_apps(&in_apps)).unwrap();
+}
+
+fn load(path: &str) -> Value {
+    let s = std::fs::read_to_string(path).unwrap_or_else(|e| panic!("{path}: {e}"));
+    serde_yaml::from_str(&s).unwrap_or_else(|e| panic!("{path}: {e}"))
+}
+
+// `pub(crate) const NAME: &[(&str, [Option<&str>; N])]`
+fn gen_table(out: &mut String, name: &str, doc: &Value, key: &str, fields: &[&str]) {
+    let list = doc
+        .get(key)
+        .and_then(|a| a.as_sequence())
+        .unwrap_or_else(|| panic!("{key}: not found"));
+    let n = fields.len();
+    writeln!(
+        out,
+        "pub(crate) const {name}: &[(&str, [Option<&str>; {n}])] = &["
+    )
+    .unwrap();
+    for item in list {
+        let regex = scalar(item.get("regex")).unwrap_or_else(|| panic!("{key}: no regex"));
+        let values: Vec<String> = fields
+            .iter()
+            .map(|f| match scalar(item.get(f)) {
+                Some(s) => format!("Some({s:?})"),
+                None => "None".to_string(),
+            })
+            .collect();
+        writeln!(out, "    ({regex:?}, [{}]),", values.join(", ")).unwrap();
+    }
+    out.push_str("];\n\n");
+}
+
+// `SNAPSHOT_DATE`, `FIREFOX_ESR` and `AGENTS` of `src/li/browserslist.rs`
+fn gen_agents(doc: &Value) -> String {
+    let mut out = String::new();
+    out += "// @generated by build.rs. Do not edit.\n\n";
+    let date = scalar(doc.get("date")).unwrap_or_else(|| panic!("{BROWSERSLIST_YAML}: no date"));
+    writeln!(out, "pub(crate) const SNAPSHOT_DATE: &str = {date:?};").unwrap();
+    let esr: Vec<String> = doc
+        .get("firefox_esr")
+        .and_then(|a| a.as_sequence())
+        .into_iter()
+        .flatten()
+        .filter_map(|a| scalar(Some(a)))
+        .collect();
+    writeln!(out, "pub(crate) const FIREFOX_ESR: &[&str] = &{esr:?};").unwrap();
+    let agents = doc
+        .get("agents")
+        .and_then(|a| a.as_sequence())
+        .unwrap_or_else(|| panic!("{BROWSERSLIST_YAML}: no agents"));
+    out += "pub(crate) const AGENTS: &[Agent] = &[\n";
+    for agent in agents {
+        let id = scalar(agent.get("id")).unwrap_or_else(|| panic!("{BROWSERSLIST_YAML}: no id"));
+        let names: Vec<String> = agent
+            .get("names")
+            .and_then(|a| a.as_sequence())
+            .into_iter()
+            .flatten()
+            .filter_map(|a| scalar(Some(a)))
+            .collect();
+        let dead = agent.get("dead").and_then(|a| a.as_bool()).unwrap_or(false);
+        let versions: Vec<String> = agent
+            .get("versions")
+            .and_then(|a| a.as_sequence())
+            .unwrap_or_else(|| panic!("{BROWSERSLIST_YAML}: {id}: no versions"))
+            .iter()
+            .map(|a| {
+                let version = scalar(a.get(0))
+                    .unwrap_or_else(|| panic!("{BROWSERSLIST_YAML}: {id}: no version"));
+                let usage = a.get(1).and_then(|a| a.as_f64()).unwrap_or(0.0);
+                format!("({version:?}, {usage:?})")
+            })
+            .collect();
+        writeln!(
+            out,
+            "    Agent {{ id: {id:?}, names: &{names:?}, dead: {dead}, versions: &[{}] }},",
+            versions.join(", ")
+        )
+        .unwrap();
+    }
+    out += "];\n";
+    out
+}
+
+// `OS_RELEASES` of `src/li/release.rs`, as `(family, version, ReleaseInfo)`
+fn gen_releases(doc: &Value) -> String {
+    let mut out = String::new();
+    out += "// @generated by build.rs. Do not edit.\n\n";
+    let releases = doc
+        .get("releases")
+        .and_then(|a| a.as_sequence())
+        .unwrap_or_else(|| panic!("{OS_RELEASES_YAML}: no releases"));
+    out += "pub(crate) const OS_RELEASES: &[(&str, &str, ReleaseInfo)] = &[\n";
+    for item in releases {
+        let field = |i: usize| scalar(item.get(i));
+        let family = field(0).unwrap_or_else(|| panic!("{OS_RELEASES_YAML}: no family"));
+        let version =
+            field(1).unwrap_or_else(|| panic!("{OS_RELEASES_YAML}: {family}: no version"));
+        let name = field(2).unwrap_or_else(|| panic!("{OS_RELEASES_YAML}: {version}: no name"));
+        let released = field(4).unwrap_or_else(|| panic!("{OS_RELEASES_YAML}: {name}: no date"));
+        let codename = match field(3) {
+            Some(s) => format!("Some({s:?})"),
+            None => "None".to_string(),
+        };
+        let api_level = match item.get(5).and_then(|a| a.as_u64()) {
+            Some(n) => format!("Some({n})"),
+            None => "None".to_string(),
+        };
+        writeln!(
+            out,
+            "    ({family:?}, {version:?}, ReleaseInfo {{ name: {name:?}, codename: {codename}, released: {released:?}, api_level: {api_level} }}),"
+        )
+        .unwrap();
+    }
+    out += "];\n";
+    out
+}
+
+// `DEVICE_MODELS` of `src/li/devicemodel.rs`, as `(model, manufacturer, name)`
+fn gen_models(doc: &Value) -> String {
+    let mut out = String::new();
+    out += "// @generated by build.rs. Do not edit.\n\n";
+    let models = doc
+        .get("device_models")
+        .and_then(|a| a.as_sequence())
+        .unwrap_or_else(|| panic!("{DEVICE_MODELS_YAML}: no device_models"));
+    out += "pub(crate) const DEVICE_MODELS: &[(&str, &str, &str)] = &[\n";
+    for item in models {
+        let [model, manufacturer, name] = ["model", "manufacturer", "name"]
+            .map(|f| scalar(item.get(f)).unwrap_or_else(|| panic!("{DEVICE_MODELS_YAML}: no {f}")));
+        writeln!(out, "    ({model:?}, {manufacturer:?}, {name:?}),").unwrap();
+    }
+    out += "];\n";
+    out
+}
+
+// `IN_APPS` of `src/li/inapp.rs`, as `(name, token, version token)`
+fn gen_in_apps(doc: &Value) -> String {
+    let mut out = String::new();
+    out += "// @generated by build.rs. Do not edit.\n\n";
+    let in_apps = doc
+        .get("in_apps")
+        .and_then(|a| a.as_sequence())
+        .unwrap_or_else(|| panic!("{IN_APPS_YAML}: no in_apps"));
+    out += "pub(crate) const IN_APPS: &[(&str, &str, &str)] = &[\n";
+    for item in in_apps {
+        let [name, token] = ["name", "token"]
+            .map(|f| scalar(item.get(f)).unwrap_or_else(|| panic!("{IN_APPS_YAML}: no {f}")));
+        assert!(!token.is_empty(), "{IN_APPS_YAML}: {name}: empty token");
+        let version = scalar(item.get("version")).unwrap_or_else(|| token.clone());
+        writeln!(out, "    ({name:?}, {token:?}, {version:?}),").unwrap();
+    }
+    out += "];\n";
+    out
+}
+
+// the same as `ClientKind` of `src/li/client.rs`
+fn check_kinds(doc: &Value) {
+    const KINDS: &[&str] = &[
+        "unknown",
+        "browser",
+        "search_crawler",
+        "ai_crawler",
+        "monitoring",
+        "http_library",
+        "headless_automation",
+        "feed_reader",
+        "crawler",
+    ];
+    let list = doc.get("client_parsers").and_then(|a| a.as_sequence());
+    for item in list.into_iter().flatten() {
+        let kind = scalar(item.get("kind")).unwrap_or_default();
+        assert!(
+            KINDS.contains(&kind.as_str()),
+            "{BOTS_YAML}: unknown kind: {kind:?}"
+        );
+    }
+}
+
+fn scalar(v: Option<&Value>) -> Option<String> {
+    match v? {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
@@ -0,0 +1,91 @@
+// Generates `resources/browserslist.yaml` from the `caniuse-lite` data.
+//
+// Run it with `make update-browserslist`, that installs the pinned
+// `caniuse-lite` into `target/caniuse` and writes the yaml.
+
+const caniuse = require("caniuse-lite");
+const pkg = require("caniuse-lite/package.json");
+
+// the agents of `caniuse-lite`, as `[id, names]`,
+// where the names are of the `browserslist` queries.
+const AGENTS = [
+    ["chrome", ["Chrome"]],
+    ["edge", ["Edge"]],
+    ["firefox", ["Firefox", "ff"]],
+    ["safari", ["Safari"]],
+    ["opera", ["Opera"]],
+    ["ie", ["IE", "explorer"]],
+    ["ios_saf", ["iOS", "iOS_Safari", "ios_saf"]],
+    ["android", ["Android"]],
+    ["and_chr", ["ChromeAndroid", "and_chr"]],
+    ["and_ff", ["FirefoxAndroid", "and_ff"]],
+    ["and_uc", ["UCAndroid", "and_uc"]],
+    ["samsung", ["Samsung"]],
+    ["op_mob", ["OperaMobile", "op_mob"]],
+    ["op_mini", ["OperaMini", "op_mini"]],
+];
+
+// the `dead` agents of `browserslist`, that are not in `caniuse-lite`
+const DEAD = ["ie"];
+
+// the versions of the `Firefox ESR` query of `browserslist`,
+// that are not in `caniuse-lite`; update them by hand.
+const FIREFOX_ESR = ["115", "140"];
+
+// the `Android` browser before 37 is not the `Chrome Mobile WebView`
+const ANDROID_WEBVIEW = 37;
+
+function usage_of (agent, version) {
+    const usage = Math.round((agent.usage_global[version] || 0) * 100) / 100;
+    return Number.isInteger(usage) ? usage.toFixed(1) : String(usage);
+}
+
+// the released versions, or the versions in use, from the oldest
+function versions_of (id, agent) {
+    return agent.versions.filter(function (version) {
+        if (version === null) {
+            return false;
+        }
+        if (id === "android" && parseFloat(version) < ANDROID_WEBVIEW) {
+            return false;
+        }
+        return agent.release_date[version] || usage_of(agent, version) !== "0.0";
+    });
+}
+
+function quote (list) {
+    return "[" + list.map(function (s) { return "'" + s + "'"; }).join(", ") + "]";
+}
+
+const lines = [
+    "# The snapshot of the browser versions and their global usage,",
+    "# for the `browserslist` queries of `browserinfo`.",
+    "#",
+    "# @generated by `scripts/browserslist.js` with `make update-browserslist`,",
+    "# from the `caniuse-lite` data of `source` at `date`. Do not edit.",
+    "# The usage is rounded to 0.01 %. It is converted into the rust tables by `build.rs`.",
+    "# `versions` are from the oldest, as `[version, usage in %]`.",
+    "# A version may be a range, as `18.5-18.6`, or `all`.",
+    "# `dead` is the browser without the updates for 24 months.",
+    "#",
+    "date: '" + new Date().toISOString().slice(0, 10) + "'",
+    "source: 'caniuse-lite " + pkg.version + "'",
+    "firefox_esr: " + quote(FIREFOX_ESR),
+    "agents:",
+];
+for (const [id, names] of AGENTS) {
+    const agent = caniuse.agents[id];
+    if (!agent) {
+        throw new Error(id + ": not found in caniuse-lite");
+    }
+    lines.push("  - id: " + id);
+    lines.push("    names: " + quote(names));
+    if (DEAD.includes(id)) {
+        lines.push("    dead: true");
+    }
+    lines.push("    versions:");
+    for (const version of versions_of(id, agent)) {
+        lines.push("      - ['" + version + "', " + usage_of(agent, version) + "]");
+    }
+}
+process.stdout.write(lines.join("\n") + "\n");
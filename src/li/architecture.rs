@@ -0,0 +1,217 @@
+use super::{JsInfo, Version};
+use serde::{Deserialize, Serialize};
+
+/// The CPU architecture of the device.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum Architecture {
+    /// can not be detected
+    #[default]
+    Unknown,
+    /// a 32-bit `x86`, as `i686`
+    X86,
+    /// a 64-bit `x86`, as `amd64`
+    X86_64,
+    /// a 32-bit `ARM`, as `armv7`
+    Arm,
+    /// a 64-bit `ARM`, as `aarch64`
+    Arm64,
+}
+
+impl Architecture {
+    /// Returns the bitness, as `Some(64)` of `X86_64`.
+    pub fn bitness(&self) -> Option<u8> {
+        match self {
+            Architecture::Unknown => None,
+            Architecture::X86 | Architecture::Arm => Some(32),
+            Architecture::X86_64 | Architecture::Arm64 => Some(64),
+        }
+    }
+    /// Returns true if self is not `Unknown`.
+    pub fn is_known(&self) -> bool {
+        *self != Architecture::Unknown
+    }
+    /// Converts from the values of `Sec-CH-UA-Arch` and `Sec-CH-UA-Bitness`,
+    /// as `"arm"` and `"64"`.
+    ///
+    /// Without the bitness, it is of 64-bit, as the current devices are.
+    pub fn from_hints(arch: &str, bitness: &str) -> Self {
+        match (arch.to_ascii_lowercase().as_str(), bitness) {
+            ("x86", "32") => Architecture::X86,
+            ("x86", _) => Architecture::X86_64,
+            ("arm", "32") => Architecture::Arm,
+            ("arm", _) => Architecture::Arm64,
+            _ => Architecture::Unknown,
+        }
+    }
+    /// Refines the architecture with the information obtained with `javascript`.
+    ///
+    /// If it is `Unknown`, it is detected from `navigator.platform`,
+    /// as `Linux armv8l`, and from `navigator.oscpu` of `Firefox`,
+    /// as `Windows NT 10.0; Win64; x64`.
+    pub fn refine(self, jsinfo: &JsInfo) -> Self {
+        if self.is_known() {
+            return self;
+        }
+        match jsinfo.platform.as_str() {
+            "iPhone" | "iPad" | "iPod" => Architecture::Arm64,
+            s => from_tokens(s).unwrap_or_else(|| from_tokens(&jsinfo.oscpu).unwrap_or(self)),
+        }
+    }
+}
+
+impl std::fmt::Display for Architecture {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let s = match self {
+            Architecture::Unknown => "unknown",
+            Architecture::X86 => "x86",
+            Architecture::X86_64 => "x86_64",
+            Architecture::Arm => "arm",
+            Architecture::Arm64 => "arm64",
+        };
+        write!(f, "{s}")
+    }
+}
+
+// the lowercase tokens, the earlier has the priority.
+// `WOW64` is a 32-bit browser on a 64-bit `Windows`, that is of `X86_64`.
+// `armv8l` is a 32-bit userland on a 64-bit CPU, that is of `Arm64`.
+const ARCH_TOKENS: &[(&str, Architecture)] = &[
+    ("x86_64", Architecture::X86_64),
+    ("x86-64", Architecture::X86_64),
+    ("amd64", Architecture::X86_64),
+    ("win64", Architecture::X86_64),
+    ("wow64", Architecture::X86_64),
+    ("x64", Architecture::X86_64),
+    ("aarch64", Architecture::Arm64),
+    ("arm64", Architecture::Arm64),
+    ("armv8", Architecture::Arm64),
+    ("armv7", Architecture::Arm),
+    ("armv6", Architecture::Arm),
+    ("armv5", Architecture::Arm),
+    ("; arm;", Architecture::Arm),
+    ("i686", Architecture::X86),
+    ("i586", Architecture::X86),
+    ("i386", Architecture::X86),
+    ("x86", Architecture::X86),
+];
+
+// the iOS devices are of 64-bit only since iOS 11
+const IOS_ARM64_SINCE: u32 = 11;
+
+fn from_tokens(s: &str) -> Option<Architecture> {
+    let s = s.to_ascii_lowercase();
+    ARCH_TOKENS
+        .iter()
+        .find(|(token, _)| s.contains(token))
+        .map(|(_, arch)| *arch)
+}
+
+/// Detects the architecture from the tokens of `user agent`.
+///
+/// A `Mac` says `Intel` even on the `Apple` silicon,
+/// so that it is `Unknown` without the client hints.
+pub(crate) fn detect_architecture(ua: &str, os_name: &str, os_version: &Version) -> Architecture {
+    if let Some(arch) = from_tokens(ua) {
+        return arch;
+    }
+    match (os_name, os_version.major) {
+        ("iOS" | "iPadOS", Some(major)) if major >= IOS_ARM64_SINCE => Architecture::Arm64,
+        _ => Architecture::Unknown,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    fn detect(ua: &str, os_name: &str, os_version: &str) -> Architecture {
+        detect_architecture(ua, os_name, &Version::parse(os_version))
+    }
+    #[test]
+    fn test_detect() {
+        assert_eq!(
+            detect("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/142.0.0.0 Safari/537.36", "Windows", "10"),
+            Architecture::X86_64
+        );
+        assert_eq!(
+            detect(
+                "Mozilla/5.0 (Windows NT 6.1; WOW64; Trident/7.0; rv:11.0) like Gecko",
+                "Windows",
+                "7"
+            ),
+            Architecture::X86_64
+        );
+        assert_eq!(
+            detect(
+                "Mozilla/5.0 (X11; Linux aarch64; rv:128.0) Gecko/20100101 Firefox/128.0",
+                "Linux",
+                ""
+            ),
+            Architecture::Arm64
+        );
+        assert_eq!(
+            detect("Mozilla/5.0 (X11; Linux armv7l) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36", "Linux", ""),
+            Architecture::Arm
+        );
+        assert_eq!(
+            detect(
+                "Mozilla/5.0 (X11; Linux i686; rv:109.0) Gecko/20100101 Firefox/115.0",
+                "Linux",
+                ""
+            ),
+            Architecture::X86
+        );
+        assert_eq!(
+            detect("Mozilla/5.0 (iPhone; CPU iPhone OS 17_5 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.5 Mobile/15E148 Safari/604.1", "iOS", "17.5"),
+            Architecture::Arm64
+        );
+        assert_eq!(
+            detect("Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.5 Safari/605.1.15", "Mac OS X", "10.15.7"),
+            Architecture::Unknown
+        );
+        assert_eq!(
+            detect("Mozilla/5.0 (Linux; Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/142.0.0.0 Mobile Safari/537.36", "Android", "10"),
+            Architecture::Unknown
+        );
+    }
+    #[test]
+    fn test_refine() {
+        let js = |platform: &str, oscpu: &str| JsInfo {
+            platform: platform.to_string(),
+            oscpu: oscpu.to_string(),
+            ..Default::default()
+        };
+        let unknown = Architecture::Unknown;
+        assert_eq!(unknown.refine(&js("Linux armv8l", "")), Architecture::Arm64);
+        assert_eq!(
+            unknown.refine(&js("Linux x86_64", "")),
+            Architecture::X86_64
+        );
+        assert_eq!(unknown.refine(&js("iPhone", "")), Architecture::Arm64);
+        assert_eq!(
+            unknown.refine(&js("Win32", "Windows NT 10.0; Win64; x64")),
+            Architecture::X86_64
+        );
+        assert_eq!(unknown.refine(&js("Win32", "")), Architecture::Unknown);
+        assert_eq!(
+            unknown.refine(&js("MacIntel", "Intel Mac OS X 10.15")),
+            unknown
+        );
+        let arm = Architecture::Arm;
+        assert_eq!(arm.refine(&js("Linux x86_64", "")), arm);
+    }
+    #[test]
+    fn test_hints() {
+        assert_eq!(Architecture::from_hints("x86", "64"), Architecture::X86_64);
+        assert_eq!(Architecture::from_hints("x86", "32"), Architecture::X86);
+        assert_eq!(Architecture::from_hints("arm", "64"), Architecture::Arm64);
+        assert_eq!(Architecture::from_hints("arm", ""), Architecture::Arm64);
+        assert_eq!(Architecture::from_hints("", "64"), Architecture::Unknown);
+        assert_eq!(Architecture::X86_64.bitness(), Some(64));
+        assert_eq!(Architecture::Arm.bitness(), Some(32));
+        assert_eq!(Architecture::Unknown.bitness(), None);
+        let s = serde_json::to_string(&Architecture::X86_64).unwrap();
+        assert_eq!(s, r#""x86_64""#);
+        assert_eq!(Architecture::Arm64.to_string(), "arm64");
+    }
+}
@@ -0,0 +1,108 @@
+use super::{try_init, Browser, RegexesError, UaParser};
+use std::collections::HashMap;
+
+/// Parses many `user agent` strings with the shared parser.
+///
+/// The results are in the input order.
+/// See [`UaParser::parse_many()`].
+pub fn parse_many<I>(uas: I) -> std::result::Result<Vec<Browser>, RegexesError>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    Ok(try_init()?.parse_many(uas))
+}
+
+impl UaParser {
+    /// Parses many `user agent` strings, as lines of the access logs.
+    ///
+    /// The identical strings are parsed only once,
+    /// and the results are in the input order.
+    /// With the `parallel` feature, the parsing is spread across threads.
+    pub fn parse_many<I>(&self, uas: I) -> Vec<Browser>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let uas: Vec<I::Item> = uas.into_iter().collect();
+        let mut index: HashMap<&str, usize> = HashMap::new();
+        let mut uniques: Vec<&str> = Vec::new();
+        let positions: Vec<usize> = uas
+            .iter()
+            .map(|ua| {
+                let ua = ua.as_ref();
+                *index.entry(ua).or_insert_with(|| {
+                    uniques.push(ua);
+                    uniques.len() - 1
+                })
+            })
+            .collect();
+        let parsed = self.parse_uniques(&uniques);
+        positions.into_iter().map(|i| parsed[i].clone()).collect()
+    }
+    #[cfg(not(feature = "parallel"))]
+    fn parse_uniques(&self, uniques: &[&str]) -> Vec<Browser> {
+        uniques.iter().map(|ua| self.parse(ua)).collect()
+    }
+    #[cfg(feature = "parallel")]
+    fn parse_uniques(&self, uniques: &[&str]) -> Vec<Browser> {
+        // a few strings are faster without threads
+        const MIN_CHUNK: usize = 64;
+        let threads = std::thread::available_parallelism()
+            .map(|a| a.get())
+            .unwrap_or(1);
+        let chunk = uniques.len().div_ceil(threads).max(MIN_CHUNK);
+        if chunk >= uniques.len() {
+            return uniques.iter().map(|ua| self.parse(ua)).collect();
+        }
+        std::thread::scope(|s| {
+            let handles: Vec<_> = uniques
+                .chunks(chunk)
+                .map(|uas| s.spawn(move || uas.iter().map(|ua| self.parse(ua)).collect::<Vec<_>>()))
+                .collect();
+            handles
+                .into_iter()
+                .flat_map(|h| h.join().unwrap_or_else(|e| std::panic::resume_unwind(e)))
+                .collect()
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[cfg(feature = "extractor")]
+    #[test]
+    fn test_parse_many() {
+        let uas = [
+            "Mozilla/5.0 (X11; Ubuntu; Linux x86_64; rv:145.0) Gecko/20100101 Firefox/145.0",
+            "curl/8.5.0",
+            "Mozilla/5.0 (X11; Ubuntu; Linux x86_64; rv:145.0) Gecko/20100101 Firefox/145.0",
+            "",
+        ];
+        let browsers = parse_many(uas).unwrap();
+        assert_eq!(browsers.len(), 4);
+        assert_eq!(browsers[0].name, "Firefox");
+        assert_eq!(browsers[1].name, "curl");
+        assert_eq!(browsers[2], browsers[0]);
+        assert_eq!(browsers[3].name, "");
+        assert!(parse_many(Vec::<String>::new()).unwrap().is_empty());
+    }
+    #[test]
+    fn test_order() {
+        // more than a chunk of the threads
+        let uas: Vec<String> = (0..500)
+            .map(|i| {
+                format!(
+                    "Mozilla/5.0 (X11; Linux x86_64; rv:{0}.0) Gecko/20100101 Firefox/{0}.0",
+                    i % 300
+                )
+            })
+            .collect();
+        let browsers = try_init().unwrap().parse_many(&uas);
+        assert_eq!(browsers.len(), uas.len());
+        for (i, browser) in browsers.iter().enumerate() {
+            assert_eq!(browser.version.major, Some((i % 300) as u32));
+        }
+    }
+}
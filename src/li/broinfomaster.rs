@@ -1,4 +1,19 @@
-use anyhow::Result;
+#[cfg(feature = "extractor")]
+use super::architecture::detect_architecture;
+#[cfg(feature = "extractor")]
+use super::deviceclass::classify;
+#[cfg(feature = "extractor")]
+use super::engine::detect_engine;
+#[cfg(feature = "extractor")]
+use super::inapp::detect_in_app;
+#[cfg(feature = "extractor")]
+use super::osversion::is_exact;
+#[cfg(feature = "extractor")]
+use super::runtime::detect_runtime;
+use super::{
+    Architecture, Brand, Client, ClientHints, DeviceClass, Engine, InApp, RegexesError, Result,
+    Runtime, UaParser, Version,
+};
 use serde::{Deserialize, Serialize};
 
 /// This is information obtained with `javascript`
@@ -6,6 +21,8 @@ use serde::{Deserialize, Serialize};
 pub struct BroInfo {
     pub basic: Basic,
     pub jsinfo: JsInfo,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ua_data: Option<UaData>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
@@ -74,6 +91,42 @@ pub struct JsInfo {
     pub has_session_storage: bool,
     pub is_dark_mode: bool,
     pub timezone: String,
+    // it is omitted to keep the json of the older version
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_touch_points: Option<i32>,
+}
+
+/// This is `navigator.userAgentData` obtained with `javascript`.
+/// Only `Chromium` based browsers have it.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+#[serde(default)]
+pub struct UaData {
+    pub brands: Vec<Brand>,
+    pub mobile: bool,
+    pub platform: String,
+    // the high entropy values
+    pub model: String,
+    pub platform_version: Version,
+    pub architecture: String,
+    pub bitness: String,
+    pub full_version_list: Vec<Brand>,
+    pub wow64: bool,
+}
+
+impl UaData {
+    /// Converts to the same hints as the `Sec-CH-UA*` headers.
+    pub fn to_client_hints(&self) -> ClientHints {
+        ClientHints {
+            brands: self.brands.clone(),
+            full_version_list: self.full_version_list.clone(),
+            platform: self.platform.clone(),
+            platform_version: self.platform_version.clone(),
+            model: self.model.clone(),
+            arch: self.architecture.clone(),
+            bitness: self.bitness.clone(),
+            mobile: Some(self.mobile),
+        }
+    }
 }
 
 /// The browser information.
@@ -83,11 +136,23 @@ pub struct Browser {
     /// a browser name
     pub name: String,
     /// a browser version
-    pub version: String,
+    pub version: Version,
+    /// a rendering engine
+    pub engine: Engine,
     /// a operating system
     pub os: Option<Os>,
-    /// a device model
-    pub device: String,
+    /// a device
+    pub device: Device,
+    /// a device class, as the form factor
+    pub device_class: DeviceClass,
+    /// a CPU architecture, with the bitness
+    pub architecture: Architecture,
+    /// a runtime, as a browser tab or an embedded web view
+    pub runtime: Runtime,
+    /// a host app of the in-app browser, as `Instagram`
+    pub in_app: Option<InApp>,
+    /// a client kind, as a browser or a crawler
+    pub client: Client,
 }
 
 /// The operating system information.
@@ -97,14 +162,78 @@ pub struct Os {
     /// a operating system name
     pub name: String,
     /// a operating system version
-    pub version: String,
+    pub version: Version,
+    /// true if the version is exact, false if it is inferred or frozen,
+    /// as `10` of `Windows 10` and `Windows 11`
+    pub exact: bool,
+}
+
+/// The device information.
+/// This is the information obtained by parsing `user agent`
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct Device {
+    /// a device family
+    pub family: String,
+    /// a device brand, as the manufacturer
+    pub brand: String,
+    /// a device model
+    pub model: String,
+    /// a manufacturer of the model database, as `Samsung`
+    pub manufacturer: String,
+    /// a marketing name of the model database, as `Galaxy S23 Ultra`
+    pub marketing_name: String,
+}
+
+impl Device {
+    /// Returns true if all of the parts are empty.
+    pub fn is_empty(&self) -> bool {
+        self.family.is_empty() && self.brand.is_empty() && self.model.is_empty()
+    }
+    /// Returns the combined string, as `Samsung/SM-S918B`.
+    ///
+    /// This is `brand/model` or `family`, whichever is longer.
+    pub fn combined(&self) -> String {
+        let brand_model = match (self.brand.is_empty(), self.model.is_empty()) {
+            (false, false) => format!("{}/{}", self.brand, self.model),
+            (false, true) => self.brand.clone(),
+            (true, false) => self.model.clone(),
+            (true, true) => String::new(),
+        };
+        if brand_model.len() >= self.family.len() {
+            brand_model
+        } else {
+            self.family.clone()
+        }
+    }
+}
+
+impl std::fmt::Display for Device {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.combined())
+    }
 }
 
 impl BroInfo {
     /// Parses `user agent` and generates `Browser`.
-    //#[allow(dead_code)]
+    ///
+    /// If there is `ua_data`, it refines the result.
     pub fn to_browser(&self) -> Result<Browser> {
-        convert_from_user_agent(self.basic.user_agent.get())
+        self.to_browser_with(get_extractor()?)
+    }
+    /// Parses `user agent` with the `parser` and generates `Browser`.
+    pub fn to_browser_with(&self, parser: &UaParser) -> Result<Browser> {
+        let user_agent = self.basic.user_agent.get();
+        let mut browser = parser.parse(user_agent);
+        if let Some(ua_data) = &self.ua_data {
+            ua_data.to_client_hints().refine(&mut browser);
+            // the model of the client hints
+            parser.models.name(&mut browser.device);
+        }
+        browser.os = browser.os.map(|a| a.refine(user_agent, &self.jsinfo));
+        let os_name = browser.os.as_ref().map(|a| a.name.as_str()).unwrap_or("");
+        browser.device_class = browser.device_class.refine(os_name, &self.jsinfo);
+        browser.architecture = browser.architecture.refine(&self.jsinfo);
+        Ok(browser)
     }
 }
 
@@ -123,134 +252,164 @@ macro_rules! ImplFromJsonStr {
 ImplFromJsonStr!(BroInfo);
 ImplFromJsonStr!(Basic);
 ImplFromJsonStr!(JsInfo);
+ImplFromJsonStr!(UaData);
 ImplFromJsonStr!(Browser);
 ImplFromJsonStr!(Os);
+ImplFromJsonStr!(Device);
+ImplFromJsonStr!(Engine);
+ImplFromJsonStr!(Client);
+ImplFromJsonStr!(InApp);
+
+static EXTRACTOR: std::sync::OnceLock<UaParser> = std::sync::OnceLock::new();
+static EXTRACTOR_INIT: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+/// Compiles the bundled regexes, if it is not done yet.
+///
+/// It is done on the first `to_browser()` without this.
+/// On failure, nothing is stored and it can be called again.
+pub fn try_init() -> std::result::Result<&'static UaParser, RegexesError> {
+    if let Some(parser) = EXTRACTOR.get() {
+        return Ok(parser);
+    }
+    // only one thread compiles
+    let _guard = EXTRACTOR_INIT.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(parser) = EXTRACTOR.get() {
+        return Ok(parser);
+    }
+    let parser = UaParser::new()?;
+    Ok(EXTRACTOR.get_or_init(|| parser))
+}
 
-//
-// To get the latest `regexes.yaml` from the `ua` parser community:
-//   git submodule update --init
-//
-static EXTRACTOR: std::sync::LazyLock<ua_parser::Extractor> = std::sync::LazyLock::new(|| {
-    let s = include_str!("../../resources/regexes.yaml");
-    let regexes: ua_parser::Regexes = serde_yaml::from_str(s).unwrap();
-    ua_parser::Extractor::try_from(regexes).unwrap()
-});
+/// Sets the shared parser of `to_browser()`, as a parser with the cache.
+///
+/// If the shared parser is already set, the `parser` is returned as the error.
+///
+/// # Examples
+/// ```rust
+/// use browserinfo::UaParser;
+///
+/// # fn func() -> anyhow::Result<()> {
+/// let parser = UaParser::builder().cache_size(4096).build()?;
+/// let _ = browserinfo::init_with(parser);
+/// # Ok(())
+/// # }
+/// ```
+pub fn init_with(parser: UaParser) -> std::result::Result<&'static UaParser, Box<UaParser>> {
+    let _guard = EXTRACTOR_INIT.lock().unwrap_or_else(|e| e.into_inner());
+    if EXTRACTOR.get().is_some() {
+        return Err(Box::new(parser));
+    }
+    Ok(EXTRACTOR.get_or_init(|| parser))
+}
 
-#[allow(dead_code)]
-fn get_extractor<'a>() -> Result<&'a ua_parser::Extractor<'a>> {
-    Ok(&*EXTRACTOR)
+/// Compiles the bundled regexes at startup,
+/// instead of on the first `to_browser()`.
+pub fn preload() -> std::result::Result<(), RegexesError> {
+    try_init().map(|_| ())
+}
+
+fn get_extractor<'a>() -> Result<&'a UaParser> {
+    Ok(try_init()?)
 }
 
 #[allow(dead_code)]
 fn convert_from_user_agent(ua: &str) -> Result<Browser> {
-    let extractor = get_extractor()?;
-    let (browser, os, device) = extractor.extract(ua);
+    let parser = get_extractor()?;
+    Ok(parser.parse(ua))
+}
+
+// the result of `ua_parser::Extractor::extract()`
+#[cfg(feature = "extractor")]
+pub(crate) type Extracted<'a> = (
+    Option<ua_parser::user_agent::ValueRef<'a>>,
+    Option<ua_parser::os::ValueRef<'a>>,
+    Option<ua_parser::device::ValueRef<'a>>,
+);
+
+#[cfg(feature = "extractor")]
+pub(crate) fn convert_extracted(ua: &str, extracted: Extracted) -> Browser {
+    let (browser, os, device) = extracted;
 
     let (name, version) = if let Some(browser) = browser {
         let name = format!("{}", browser.family);
-        let version = if let Some(major) = browser.major {
-            if let Some(minor) = browser.minor {
-                if let Some(patch) = browser.patch {
-                    if let Some(patch_minor) = browser.patch_minor {
-                        format!("{major}.{minor}.{patch}.{patch_minor}")
-                    } else {
-                        format!("{major}.{minor}.{patch}")
-                    }
-                } else {
-                    format!("{major}.{minor}")
-                }
-            } else {
-                major.to_string()
-            }
-        } else {
-            String::new()
-        };
+        let version = Version::from_parts([
+            browser.major,
+            browser.minor,
+            browser.patch,
+            browser.patch_minor,
+        ]);
         (name, version)
     } else {
-        (String::new(), String::new())
+        (String::new(), Version::default())
     };
 
     let (os_name, os_version) = if let Some(os) = os {
         let os_name = format!("{}", os.os);
-        let os_version = if let Some(major) = os.major {
-            if let Some(minor) = os.minor {
-                if let Some(patch) = os.patch {
-                    if let Some(patch_minor) = os.patch_minor {
-                        format!("{major}.{minor}.{patch}.{patch_minor}")
-                    } else {
-                        format!("{major}.{minor}.{patch}")
-                    }
-                } else {
-                    format!("{major}.{minor}")
-                }
-            } else {
-                format!("{major}")
-            }
-        } else {
-            String::new()
-        };
+        let os_version = Version::from_parts([
+            os.major.as_deref(),
+            os.minor.as_deref(),
+            os.patch.as_deref(),
+            os.patch_minor.as_deref(),
+        ]);
         (os_name, os_version)
     } else {
-        (String::new(), String::new())
+        (String::new(), Version::default())
     };
 
-    let device_model = if let Some(dev) = device {
-        let brand_model = if let Some(brand) = dev.brand {
-            if let Some(model) = dev.model {
-                if let Some(idx) = model.rfind(r" Build/") {
-                    format!("{brand}/{}", &model[..idx])
-                } else {
-                    format!("{brand}/{model}")
-                }
-            } else {
-                format!("{brand}")
-            }
-        } else if let Some(model) = dev.model {
-            if let Some(idx) = model.rfind(r" Build/") {
-                model[..idx].to_string()
+    let device = if let Some(dev) = device {
+        let strip_build = |s: &str| {
+            if let Some(idx) = s.rfind(r" Build/") {
+                s[..idx].to_string()
             } else {
-                model.to_string()
+                s.to_string()
             }
-        } else {
-            String::new()
-        };
-        let devdev = if let Some(idx) = dev.device.rfind(r" Build/") {
-            dev.device[..idx].to_string()
-        } else {
-            dev.device.to_string()
         };
-        if brand_model.len() >= devdev.len() {
-            brand_model
-        } else {
-            devdev
+        Device {
+            family: strip_build(&dev.device),
+            brand: dev.brand.map(|a| a.to_string()).unwrap_or_default(),
+            model: dev.model.map(|a| strip_build(&a)).unwrap_or_default(),
+            ..Default::default()
         }
     } else {
-        String::new()
+        Device::default()
     };
 
+    let device_class = classify(ua, &os_name, &device);
+    let engine = detect_engine(ua, &os_name);
+    let architecture = detect_architecture(ua, &os_name, &os_version);
+    let runtime = detect_runtime(ua, &name, &os_name);
+    let in_app = detect_in_app(ua);
+
     //
-    Ok(Browser {
+    Browser {
         name,
         version,
+        engine,
         os: Some(Os {
+            exact: is_exact(ua, &os_name, &os_version),
             name: os_name,
             version: os_version,
         }),
-        device: device_model,
-    })
+        device,
+        device_class,
+        architecture,
+        runtime,
+        in_app,
+        client: Client::default(),
+    }
 }
 
 // for test
 #[allow(unused_macros)]
 macro_rules! break_dump_extractor {
     ($ua:expr) => {{
-        let extractor = get_extractor().unwrap();
-        let (browser, os, device) = extractor.extract($ua);
-        assert_eq!(format!("{browser:?},{os:?},{device:?}"), "");
+        let ex = get_extractor().unwrap().explain($ua);
+        assert_eq!(ex.to_string(), "");
     }};
 }
 
-#[cfg(test)]
+// the expected values are of the full parser
+#[cfg(all(test, feature = "extractor"))]
 mod test {
     use super::*;
     #[test]
@@ -274,17 +433,112 @@ mod test {
         assert_eq!(broinfo2, broinfo);
     }
     #[test]
+    fn test_03() {
+        let s0 = r#"{"basic":{"user_agent":"Mozilla/5.0 (Linux; Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/142.0.0.0 Mobile Safari/537.36","referrer":""},"jsinfo":{"oscpu":"","platform":"Linux armv8l","cpu_cores":8,"cookie_enabled":true,"user_language":"ja","device_memory":8,"screen_width":412,"screen_height":915,"screen_color_depth":24,"device_pixcel_ratio":2.625,"has_local_storage":true,"has_session_storage":true,"is_dark_mode":false,"timezone":"Asia/Tokyo"},"ua_data":{"brands":[{"brand":"Chromium","version":"142"},{"brand":"Google Chrome","version":"142"},{"brand":"Not_A Brand","version":"99"}],"mobile":true,"platform":"Android","model":"Pixel 7","platform_version":"14.0.0","architecture":"","bitness":"64","full_version_list":[{"brand":"Chromium","version":"142.0.7444.102"},{"brand":"Google Chrome","version":"142.0.7444.102"},{"brand":"Not_A Brand","version":"99.0.0.0"}],"wow64":false}}"#;
+        let broinfo = BroInfo::from_json_str(s0).unwrap();
+        let s = serde_json::to_string(&broinfo).unwrap();
+        assert_eq!(s, s0);
+        let browser = broinfo.to_browser().unwrap();
+        let target = concat!(
+            r#"Browser { name: "Chrome Mobile", version: "142.0.7444.102", "#,
+            r#"engine: Engine { name: "Blink", version: "142.0.7444.102" }, "#,
+            r#"os: Some(Os { name: "Android", version: "14.0.0", exact: true }), "#,
+            r#"device: Device { family: "Pixel 7", brand: "", model: "Pixel 7", manufacturer: "Google", marketing_name: "Pixel 7" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Arm64, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
+        );
+        assert_eq!(format!("{browser:?}"), target);
+    }
+    #[test]
+    fn test_04() {
+        // the low entropy values only
+        let s0 = r#"{"basic":{"user_agent":"Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/142.0.0.0 Safari/537.36 Edg/142.0.0.0","referrer":""},"jsinfo":{"oscpu":"","platform":"Win32","cpu_cores":8,"cookie_enabled":true,"user_language":"ja","device_memory":8,"screen_width":1920,"screen_height":1080,"screen_color_depth":24,"device_pixcel_ratio":1.0,"has_local_storage":true,"has_session_storage":true,"is_dark_mode":false,"timezone":"Asia/Tokyo"},"ua_data":{"brands":[{"brand":"Microsoft Edge","version":"142"},{"brand":"Chromium","version":"142"},{"brand":"Not_A Brand","version":"99"}],"mobile":false,"platform":"Windows"}}"#;
+        let broinfo = BroInfo::from_json_str(s0).unwrap();
+        let ua_data = broinfo.ua_data.as_ref().unwrap();
+        assert_eq!(ua_data.model, "");
+        assert!(ua_data.full_version_list.is_empty());
+        let browser = broinfo.to_browser().unwrap();
+        let target = concat!(
+            r#"Browser { name: "Edge", version: "142.0.0.0", "#,
+            r#"engine: Engine { name: "Blink", version: "142.0.0.0" }, "#,
+            r#"os: Some(Os { name: "Windows", version: "10", exact: false }), "#,
+            r#"device: Device { family: "", brand: "", model: "", manufacturer: "", marketing_name: "" }, "#,
+            r#"device_class: Desktop, "#,
+            r#"architecture: X86_64, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
+        );
+        assert_eq!(format!("{browser:?}"), target);
+    }
+    #[test]
+    fn test_try_init() {
+        preload().unwrap();
+        let parser1 = try_init().unwrap();
+        let parser2 = try_init().unwrap();
+        assert!(std::ptr::eq(parser1, parser2));
+        // already set
+        let parser = UaParser::builder().build().unwrap();
+        assert!(init_with(parser).is_err());
+    }
+    #[test]
     fn test_user_agent_00() {
         let s0 = r#""#;
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "", version: "", "#,
-            r#"os: Some(Os { name: "", version: "" }), "#,
-            r#"device: "" }"#,
+            r#"engine: Engine { name: "", version: "" }, "#,
+            r#"os: Some(Os { name: "", version: "", exact: false }), "#,
+            r#"device: Device { family: "", brand: "", model: "", manufacturer: "", marketing_name: "" }, "#,
+            r#"device_class: Unknown, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: Unknown, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Unknown, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
     }
     #[test]
+    fn test_user_agent_version_01() {
+        let s0 = r#"Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.6099.230 Safari/537.36"#;
+        let browser = convert_from_user_agent(s0).unwrap();
+        assert_eq!(browser.version.major, Some(120));
+        assert!(browser.version >= Version::parse("120"));
+        assert!(browser.version < Version::parse("121"));
+        let os = browser.os.unwrap();
+        assert_eq!(os.version.parts(), vec![10, 15, 7]);
+        assert!(os.version > Version::parse("10.9"));
+    }
+    #[test]
+    fn test_device_01() {
+        let s0 = r#"Mozilla/5.0 (Linux; Android 15; SM-S931B Build/AP3A.240905.015.A2; wv) AppleWebKit/537.36 (KHTML, like Gecko) Version/4.0 Chrome/127.0.6533.103 Mobile Safari/537.36"#;
+        let browser = convert_from_user_agent(s0).unwrap();
+        assert_eq!(browser.device.brand, "Samsung");
+        assert_eq!(browser.device.model, "SM-S931B");
+        assert_eq!(browser.device.to_string(), "Samsung/SM-S931B");
+        assert_eq!(browser.device.manufacturer, "Samsung");
+        assert_eq!(browser.device.marketing_name, "Galaxy S25");
+        //
+        let device = Device {
+            family: "K".to_string(),
+            brand: "Generic_Android".to_string(),
+            model: "K".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(device.combined(), "Generic_Android/K");
+        assert!(!device.is_empty());
+        let device = Device {
+            family: "Spider".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(device.combined(), "Spider");
+        assert!(Device::default().is_empty());
+        assert_eq!(Device::default().combined(), "");
+    }
+    #[test]
     fn test_user_agent_on_linux_01() {
         // linux desktop browser: firefox
         let s0 =
@@ -292,8 +546,14 @@ mod test {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Firefox", version: "145.0", "#,
-            r#"os: Some(Os { name: "Ubuntu", version: "" }), "#,
-            r#"device: "" }"#,
+            r#"engine: Engine { name: "Gecko", version: "145.0" }, "#,
+            r#"os: Some(Os { name: "Ubuntu", version: "", exact: false }), "#,
+            r#"device: Device { family: "", brand: "", model: "", manufacturer: "", marketing_name: "" }, "#,
+            r#"device_class: Desktop, "#,
+            r#"architecture: X86_64, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
     }
@@ -304,8 +564,14 @@ mod test {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome", version: "142.0.0.0", "#,
-            r#"os: Some(Os { name: "Linux", version: "" }), "#,
-            r#"device: "" }"#,
+            r#"engine: Engine { name: "Blink", version: "142.0.0.0" }, "#,
+            r#"os: Some(Os { name: "Linux", version: "", exact: false }), "#,
+            r#"device: Device { family: "", brand: "", model: "", manufacturer: "", marketing_name: "" }, "#,
+            r#"device_class: Desktop, "#,
+            r#"architecture: X86_64, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
     }
@@ -316,8 +582,14 @@ mod test {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Safari", version: "60.5", "#,
-            r#"os: Some(Os { name: "Ubuntu", version: "" }), "#,
-            r#"device: "" }"#,
+            r#"engine: Engine { name: "WebKit", version: "605.1.15" }, "#,
+            r#"os: Some(Os { name: "Ubuntu", version: "", exact: false }), "#,
+            r#"device: Device { family: "", brand: "", model: "", manufacturer: "", marketing_name: "" }, "#,
+            r#"device_class: Desktop, "#,
+            r#"architecture: X86_64, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
     }
@@ -329,8 +601,14 @@ mod test {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Firefox", version: "145.0", "#,
-            r#"os: Some(Os { name: "Windows", version: "10" }), "#,
-            r#"device: "" }"#,
+            r#"engine: Engine { name: "Gecko", version: "145.0" }, "#,
+            r#"os: Some(Os { name: "Windows", version: "10", exact: false }), "#,
+            r#"device: Device { family: "", brand: "", model: "", manufacturer: "", marketing_name: "" }, "#,
+            r#"device_class: Desktop, "#,
+            r#"architecture: X86_64, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
     }
@@ -341,8 +619,14 @@ mod test {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Edge", version: "142.0.0.0", "#,
-            r#"os: Some(Os { name: "Windows", version: "10" }), "#,
-            r#"device: "" }"#,
+            r#"engine: Engine { name: "Blink", version: "142.0.0.0" }, "#,
+            r#"os: Some(Os { name: "Windows", version: "10", exact: false }), "#,
+            r#"device: Device { family: "", brand: "", model: "", manufacturer: "", marketing_name: "" }, "#,
+            r#"device_class: Desktop, "#,
+            r#"architecture: X86_64, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
     }
@@ -353,8 +637,14 @@ mod test {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile", version: "142.0.0.0", "#,
-            r#"os: Some(Os { name: "Android", version: "10" }), "#,
-            r#"device: "Generic_Android/K" }"#,
+            r#"engine: Engine { name: "Blink", version: "142.0.0.0" }, "#,
+            r#"os: Some(Os { name: "Android", version: "10", exact: false }), "#,
+            r#"device: Device { family: "K", brand: "Generic_Android", model: "K", manufacturer: "", marketing_name: "" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
     }
@@ -365,8 +655,14 @@ mod test {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile WebView", version: "142.0.7444.102", "#,
-            r#"os: Some(Os { name: "Android", version: "11" }), "#,
-            r#"device: "Generic_Android/S5-SH" }"#,
+            r#"engine: Engine { name: "Blink", version: "142.0.7444.102" }, "#,
+            r#"os: Some(Os { name: "Android", version: "11", exact: true }), "#,
+            r#"device: Device { family: "S5-SH", brand: "Generic_Android", model: "S5-SH", manufacturer: "", marketing_name: "" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: AndroidWebView, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
         //break_dump_extractor!(s0);
@@ -378,8 +674,14 @@ mod test {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile", version: "142.0.0.0", "#,
-            r#"os: Some(Os { name: "Android", version: "10" }), "#,
-            r#"device: "Generic_Android/K" }"#,
+            r#"engine: Engine { name: "Blink", version: "142.0.0.0" }, "#,
+            r#"os: Some(Os { name: "Android", version: "10", exact: false }), "#,
+            r#"device: Device { family: "K", brand: "Generic_Android", model: "K", manufacturer: "", marketing_name: "" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
     }
@@ -390,8 +692,14 @@ mod test {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile WebView", version: "141.0.7390.122", "#,
-            r#"os: Some(Os { name: "Android", version: "11" }), "#,
-            r#"device: "Generic_Android/sdk_gphone_x86_64" }"#,
+            r#"engine: Engine { name: "Blink", version: "141.0.7390.122" }, "#,
+            r#"os: Some(Os { name: "Android", version: "11", exact: true }), "#,
+            r#"device: Device { family: "sdk_gphone_x86_64", brand: "Generic_Android", model: "sdk_gphone_x86_64", manufacturer: "", marketing_name: "" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: X86_64, "#,
+            r#"runtime: AndroidWebView, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
     }
@@ -402,8 +710,14 @@ mod test {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile", version: "109.0.0.0", "#,
-            r#"os: Some(Os { name: "Android", version: "13" }), "#,
-            r#"device: "Generic_Android/sdk_gphone64_x86_64" }"#,
+            r#"engine: Engine { name: "Blink", version: "109.0.0.0" }, "#,
+            r#"os: Some(Os { name: "Android", version: "13", exact: true }), "#,
+            r#"device: Device { family: "sdk_gphone64_x86_64", brand: "Generic_Android", model: "sdk_gphone64_x86_64", manufacturer: "", marketing_name: "" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: X86_64, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
     }
@@ -414,14 +728,21 @@ mod test {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile WebView", version: "142.0.7444.102", "#,
-            r#"os: Some(Os { name: "Android", version: "13" }), "#,
-            r#"device: "Generic_Android/sdk_gphone64_x86_64" }"#,
+            r#"engine: Engine { name: "Blink", version: "142.0.7444.102" }, "#,
+            r#"os: Some(Os { name: "Android", version: "13", exact: true }), "#,
+            r#"device: Device { family: "sdk_gphone64_x86_64", brand: "Generic_Android", model: "sdk_gphone64_x86_64", manufacturer: "", marketing_name: "" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: X86_64, "#,
+            r#"runtime: AndroidWebView, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
     }
 }
 
-#[cfg(test)]
+// the expected values are of the full parser
+#[cfg(all(test, feature = "extractor"))]
 mod test_more_01 {
     use super::*;
     #[test]
@@ -431,8 +752,14 @@ mod test_more_01 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile WebView", version: "127.0.6533.103", "#,
-            r#"os: Some(Os { name: "Android", version: "15" }), "#,
-            r#"device: "Samsung/SM-S931B" }"#,
+            r#"engine: Engine { name: "Blink", version: "127.0.6533.103" }, "#,
+            r#"os: Some(Os { name: "Android", version: "15", exact: true }), "#,
+            r#"device: Device { family: "Samsung SM-S931B", brand: "Samsung", model: "SM-S931B", manufacturer: "Samsung", marketing_name: "Galaxy S25" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: AndroidWebView, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
 
@@ -440,8 +767,14 @@ mod test_more_01 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile WebView", version: "132.0.6834.163", "#,
-            r#"os: Some(Os { name: "Android", version: "15" }), "#,
-            r#"device: "Samsung/SM-S931U" }"#,
+            r#"engine: Engine { name: "Blink", version: "132.0.6834.163" }, "#,
+            r#"os: Some(Os { name: "Android", version: "15", exact: true }), "#,
+            r#"device: Device { family: "Samsung SM-S931U", brand: "Samsung", model: "SM-S931U", manufacturer: "Samsung", marketing_name: "Galaxy S25" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: AndroidWebView, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
     }
@@ -452,8 +785,14 @@ mod test_more_01 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile", version: "120.0.6099.230", "#,
-            r#"os: Some(Os { name: "Android", version: "14" }), "#,
-            r#"device: "Generic_Android/SM-S928B/DS" }"#,
+            r#"engine: Engine { name: "Blink", version: "120.0.6099.230" }, "#,
+            r#"os: Some(Os { name: "Android", version: "14", exact: true }), "#,
+            r#"device: Device { family: "SM-S928B/DS", brand: "Generic_Android", model: "SM-S928B/DS", manufacturer: "Samsung", marketing_name: "Galaxy S24 Ultra" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
         //break_dump_extractor!(s0);
@@ -462,8 +801,14 @@ mod test_more_01 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile", version: "120.0.6099.230", "#,
-            r#"os: Some(Os { name: "Android", version: "14" }), "#,
-            r#"device: "Samsung/SM-S928W" }"#,
+            r#"engine: Engine { name: "Blink", version: "120.0.6099.230" }, "#,
+            r#"os: Some(Os { name: "Android", version: "14", exact: true }), "#,
+            r#"device: Device { family: "Samsung SM-S928W", brand: "Samsung", model: "SM-S928W", manufacturer: "Samsung", marketing_name: "Galaxy S24 Ultra" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
     }
@@ -474,8 +819,14 @@ mod test_more_01 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile WebView", version: "127.0.6533.103", "#,
-            r#"os: Some(Os { name: "Android", version: "14" }), "#,
-            r#"device: "Samsung/SM-F9560" }"#,
+            r#"engine: Engine { name: "Blink", version: "127.0.6533.103" }, "#,
+            r#"os: Some(Os { name: "Android", version: "14", exact: true }), "#,
+            r#"device: Device { family: "Samsung SM-F9560", brand: "Samsung", model: "SM-F9560", manufacturer: "Samsung", marketing_name: "Galaxy Z Fold6" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: AndroidWebView, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
 
@@ -483,8 +834,14 @@ mod test_more_01 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile WebView", version: "80.0.3987.119", "#,
-            r#"os: Some(Os { name: "Android", version: "14" }), "#,
-            r#"device: "Samsung/SM-F956U" }"#,
+            r#"engine: Engine { name: "Blink", version: "80.0.3987.119" }, "#,
+            r#"os: Some(Os { name: "Android", version: "14", exact: true }), "#,
+            r#"device: Device { family: "Samsung SM-F956U", brand: "Samsung", model: "SM-F956U", manufacturer: "Samsung", marketing_name: "Galaxy Z Fold6" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: AndroidWebView, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
     }
@@ -496,8 +853,14 @@ mod test_more_01 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Firefox Mobile", version: "130.0", "#,
-            r#"os: Some(Os { name: "Android", version: "15" }), "#,
-            r#"device: "Generic/Smartphone" }"#,
+            r#"engine: Engine { name: "Gecko", version: "130.0" }, "#,
+            r#"os: Some(Os { name: "Android", version: "15", exact: true }), "#,
+            r#"device: Device { family: "Generic Smartphone", brand: "Generic", model: "Smartphone", manufacturer: "", marketing_name: "" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
 
@@ -506,8 +869,14 @@ mod test_more_01 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Firefox Mobile", version: "130.0", "#,
-            r#"os: Some(Os { name: "Android", version: "15" }), "#,
-            r#"device: "Generic/Smartphone" }"#,
+            r#"engine: Engine { name: "Gecko", version: "130.0" }, "#,
+            r#"os: Some(Os { name: "Android", version: "15", exact: true }), "#,
+            r#"device: Device { family: "Generic Smartphone", brand: "Generic", model: "Smartphone", manufacturer: "", marketing_name: "" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
     }
@@ -518,8 +887,14 @@ mod test_more_01 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile", version: "104.0.0.0", "#,
-            r#"os: Some(Os { name: "Android", version: "13" }), "#,
-            r#"device: "Samsung/SM-S911B" }"#,
+            r#"engine: Engine { name: "Blink", version: "104.0.0.0" }, "#,
+            r#"os: Some(Os { name: "Android", version: "13", exact: true }), "#,
+            r#"device: Device { family: "Samsung SM-S911B", brand: "Samsung", model: "SM-S911B", manufacturer: "Samsung", marketing_name: "Galaxy S23" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
 
@@ -527,8 +902,14 @@ mod test_more_01 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile", version: "110.0.0.0", "#,
-            r#"os: Some(Os { name: "Android", version: "13" }), "#,
-            r#"device: "Samsung/SM-S911U" }"#,
+            r#"engine: Engine { name: "Blink", version: "110.0.0.0" }, "#,
+            r#"os: Some(Os { name: "Android", version: "13", exact: true }), "#,
+            r#"device: Device { family: "Samsung SM-S911U", brand: "Samsung", model: "SM-S911U", manufacturer: "Samsung", marketing_name: "Galaxy S23" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
     }
@@ -541,8 +922,14 @@ mod test_more_01 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile", version: "112.0.0.0", "#,
-            r#"os: Some(Os { name: "Android", version: "13" }), "#,
-            r#"device: "Samsung/SM-S901B" }"#,
+            r#"engine: Engine { name: "Blink", version: "112.0.0.0" }, "#,
+            r#"os: Some(Os { name: "Android", version: "13", exact: true }), "#,
+            r#"device: Device { family: "Samsung SM-S901B", brand: "Samsung", model: "SM-S901B", manufacturer: "Samsung", marketing_name: "Galaxy S22" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
 
@@ -552,8 +939,14 @@ mod test_more_01 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile", version: "112.0.0.0", "#,
-            r#"os: Some(Os { name: "Android", version: "13" }), "#,
-            r#"device: "Samsung/SM-S901U" }"#,
+            r#"engine: Engine { name: "Blink", version: "112.0.0.0" }, "#,
+            r#"os: Some(Os { name: "Android", version: "13", exact: true }), "#,
+            r#"device: Device { family: "Samsung SM-S901U", brand: "Samsung", model: "SM-S901U", manufacturer: "Samsung", marketing_name: "Galaxy S22" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
     }
@@ -564,8 +957,14 @@ mod test_more_01 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile", version: "112.0.0.0", "#,
-            r#"os: Some(Os { name: "Android", version: "13" }), "#,
-            r#"device: "Samsung/SM-S908B" }"#,
+            r#"engine: Engine { name: "Blink", version: "112.0.0.0" }, "#,
+            r#"os: Some(Os { name: "Android", version: "13", exact: true }), "#,
+            r#"device: Device { family: "Samsung SM-S908B", brand: "Samsung", model: "SM-S908B", manufacturer: "Samsung", marketing_name: "Galaxy S22 Ultra" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
 
@@ -573,8 +972,14 @@ mod test_more_01 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile", version: "111.0.0.0", "#,
-            r#"os: Some(Os { name: "Android", version: "13" }), "#,
-            r#"device: "Samsung/SM-S908U" }"#,
+            r#"engine: Engine { name: "Blink", version: "111.0.0.0" }, "#,
+            r#"os: Some(Os { name: "Android", version: "13", exact: true }), "#,
+            r#"device: Device { family: "Samsung SM-S908U", brand: "Samsung", model: "SM-S908U", manufacturer: "Samsung", marketing_name: "Galaxy S22 Ultra" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
     }
@@ -585,8 +990,14 @@ mod test_more_01 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile", version: "112.0.0.0", "#,
-            r#"os: Some(Os { name: "Android", version: "13" }), "#,
-            r#"device: "Samsung/SM-G991B" }"#,
+            r#"engine: Engine { name: "Blink", version: "112.0.0.0" }, "#,
+            r#"os: Some(Os { name: "Android", version: "13", exact: true }), "#,
+            r#"device: Device { family: "Samsung SM-G991B", brand: "Samsung", model: "SM-G991B", manufacturer: "Samsung", marketing_name: "Galaxy S21" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
 
@@ -594,8 +1005,14 @@ mod test_more_01 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile", version: "112.0.0.0", "#,
-            r#"os: Some(Os { name: "Android", version: "13" }), "#,
-            r#"device: "Samsung/SM-G991U" }"#,
+            r#"engine: Engine { name: "Blink", version: "112.0.0.0" }, "#,
+            r#"os: Some(Os { name: "Android", version: "13", exact: true }), "#,
+            r#"device: Device { family: "Samsung SM-G991U", brand: "Samsung", model: "SM-G991U", manufacturer: "Samsung", marketing_name: "Galaxy S21" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
     }
@@ -606,8 +1023,14 @@ mod test_more_01 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile", version: "112.0.0.0", "#,
-            r#"os: Some(Os { name: "Android", version: "13" }), "#,
-            r#"device: "Samsung/SM-G998B" }"#,
+            r#"engine: Engine { name: "Blink", version: "112.0.0.0" }, "#,
+            r#"os: Some(Os { name: "Android", version: "13", exact: true }), "#,
+            r#"device: Device { family: "Samsung SM-G998B", brand: "Samsung", model: "SM-G998B", manufacturer: "Samsung", marketing_name: "Galaxy S21 Ultra" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
 
@@ -615,8 +1038,14 @@ mod test_more_01 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile", version: "112.0.0.0", "#,
-            r#"os: Some(Os { name: "Android", version: "13" }), "#,
-            r#"device: "Samsung/SM-G998U" }"#,
+            r#"engine: Engine { name: "Blink", version: "112.0.0.0" }, "#,
+            r#"os: Some(Os { name: "Android", version: "13", exact: true }), "#,
+            r#"device: Device { family: "Samsung SM-G998U", brand: "Samsung", model: "SM-G998U", manufacturer: "Samsung", marketing_name: "Galaxy S21 Ultra" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
     }
@@ -629,8 +1058,14 @@ mod test_more_01 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile", version: "112.0.0.0", "#,
-            r#"os: Some(Os { name: "Android", version: "13" }), "#,
-            r#"device: "Samsung/SM-A536B" }"#,
+            r#"engine: Engine { name: "Blink", version: "112.0.0.0" }, "#,
+            r#"os: Some(Os { name: "Android", version: "13", exact: true }), "#,
+            r#"device: Device { family: "Samsung SM-A536B", brand: "Samsung", model: "SM-A536B", manufacturer: "Samsung", marketing_name: "Galaxy A53 5G" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
 
@@ -640,8 +1075,14 @@ mod test_more_01 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile", version: "112.0.0.0", "#,
-            r#"os: Some(Os { name: "Android", version: "13" }), "#,
-            r#"device: "Samsung/SM-A536U" }"#,
+            r#"engine: Engine { name: "Blink", version: "112.0.0.0" }, "#,
+            r#"os: Some(Os { name: "Android", version: "13", exact: true }), "#,
+            r#"device: Device { family: "Samsung SM-A536U", brand: "Samsung", model: "SM-A536U", manufacturer: "Samsung", marketing_name: "Galaxy A53 5G" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
     }
@@ -654,8 +1095,14 @@ mod test_more_01 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile", version: "112.0.0.0", "#,
-            r#"os: Some(Os { name: "Android", version: "13" }), "#,
-            r#"device: "Samsung/SM-A515F" }"#,
+            r#"engine: Engine { name: "Blink", version: "112.0.0.0" }, "#,
+            r#"os: Some(Os { name: "Android", version: "13", exact: true }), "#,
+            r#"device: Device { family: "Samsung SM-A515F", brand: "Samsung", model: "SM-A515F", manufacturer: "Samsung", marketing_name: "Galaxy A51" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
 
@@ -665,8 +1112,14 @@ mod test_more_01 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile", version: "112.0.0.0", "#,
-            r#"os: Some(Os { name: "Android", version: "13" }), "#,
-            r#"device: "Samsung/SM-A515U" }"#,
+            r#"engine: Engine { name: "Blink", version: "112.0.0.0" }, "#,
+            r#"os: Some(Os { name: "Android", version: "13", exact: true }), "#,
+            r#"device: Device { family: "Samsung SM-A515U", brand: "Samsung", model: "SM-A515U", manufacturer: "Samsung", marketing_name: "Galaxy A51" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
     }
@@ -679,8 +1132,14 @@ mod test_more_01 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile", version: "112.0.0.0", "#,
-            r#"os: Some(Os { name: "Android", version: "12" }), "#,
-            r#"device: "Samsung/SM-G973F" }"#,
+            r#"engine: Engine { name: "Blink", version: "112.0.0.0" }, "#,
+            r#"os: Some(Os { name: "Android", version: "12", exact: true }), "#,
+            r#"device: Device { family: "Samsung SM-G973F", brand: "Samsung", model: "SM-G973F", manufacturer: "Samsung", marketing_name: "Galaxy S10" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
 
@@ -688,14 +1147,21 @@ mod test_more_01 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile", version: "112.0.0.0", "#,
-            r#"os: Some(Os { name: "Android", version: "12" }), "#,
-            r#"device: "Samsung/SM-G973U" }"#,
+            r#"engine: Engine { name: "Blink", version: "112.0.0.0" }, "#,
+            r#"os: Some(Os { name: "Android", version: "12", exact: true }), "#,
+            r#"device: Device { family: "Samsung SM-G973U", brand: "Samsung", model: "SM-G973U", manufacturer: "Samsung", marketing_name: "Galaxy S10" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
     }
 }
 
-#[cfg(test)]
+// the expected values are of the full parser
+#[cfg(all(test, feature = "extractor"))]
 mod test_more_02 {
     use super::*;
     #[test]
@@ -705,8 +1171,14 @@ mod test_more_02 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile WebView", version: "124.0.6367.54", "#,
-            r#"os: Some(Os { name: "Android", version: "14" }), "#,
-            r#"device: "Google/Pixel 9 Pro" }"#,
+            r#"engine: Engine { name: "Blink", version: "124.0.6367.54" }, "#,
+            r#"os: Some(Os { name: "Android", version: "14", exact: true }), "#,
+            r#"device: Device { family: "Pixel 9 Pro", brand: "Google", model: "Pixel 9 Pro", manufacturer: "Google", marketing_name: "Pixel 9 Pro" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: AndroidWebView, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
 
@@ -715,8 +1187,14 @@ mod test_more_02 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile WebView", version: "124.0.6367.54", "#,
-            r#"os: Some(Os { name: "Android", version: "14" }), "#,
-            r#"device: "Google/Pixel 9" }"#,
+            r#"engine: Engine { name: "Blink", version: "124.0.6367.54" }, "#,
+            r#"os: Some(Os { name: "Android", version: "14", exact: true }), "#,
+            r#"device: Device { family: "Pixel 9", brand: "Google", model: "Pixel 9", manufacturer: "Google", marketing_name: "Pixel 9" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: AndroidWebView, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
     }
@@ -727,8 +1205,14 @@ mod test_more_02 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile WebView", version: "132.0.6834.163", "#,
-            r#"os: Some(Os { name: "Android", version: "15" }), "#,
-            r#"device: "Google/Pixel 8 Pro" }"#,
+            r#"engine: Engine { name: "Blink", version: "132.0.6834.163" }, "#,
+            r#"os: Some(Os { name: "Android", version: "15", exact: true }), "#,
+            r#"device: Device { family: "Pixel 8 Pro", brand: "Google", model: "Pixel 8 Pro", manufacturer: "Google", marketing_name: "Pixel 8 Pro" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: AndroidWebView, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
 
@@ -737,8 +1221,14 @@ mod test_more_02 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile WebView", version: "132.0.6834.163", "#,
-            r#"os: Some(Os { name: "Android", version: "15" }), "#,
-            r#"device: "Google/Pixel 8" }"#,
+            r#"engine: Engine { name: "Blink", version: "132.0.6834.163" }, "#,
+            r#"os: Some(Os { name: "Android", version: "15", exact: true }), "#,
+            r#"device: Device { family: "Pixel 8", brand: "Google", model: "Pixel 8", manufacturer: "Google", marketing_name: "Pixel 8" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: AndroidWebView, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
     }
@@ -749,8 +1239,14 @@ mod test_more_02 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile", version: "112.0.0.0", "#,
-            r#"os: Some(Os { name: "Android", version: "13" }), "#,
-            r#"device: "Google/Pixel 7 Pro" }"#,
+            r#"engine: Engine { name: "Blink", version: "112.0.0.0" }, "#,
+            r#"os: Some(Os { name: "Android", version: "13", exact: true }), "#,
+            r#"device: Device { family: "Pixel 7 Pro", brand: "Google", model: "Pixel 7 Pro", manufacturer: "Google", marketing_name: "Pixel 7 Pro" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
 
@@ -759,8 +1255,14 @@ mod test_more_02 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile", version: "112.0.0.0", "#,
-            r#"os: Some(Os { name: "Android", version: "13" }), "#,
-            r#"device: "Google/Pixel 7" }"#,
+            r#"engine: Engine { name: "Blink", version: "112.0.0.0" }, "#,
+            r#"os: Some(Os { name: "Android", version: "13", exact: true }), "#,
+            r#"device: Device { family: "Pixel 7", brand: "Google", model: "Pixel 7", manufacturer: "Google", marketing_name: "Pixel 7" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
     }
@@ -771,8 +1273,14 @@ mod test_more_02 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile", version: "112.0.0.0", "#,
-            r#"os: Some(Os { name: "Android", version: "13" }), "#,
-            r#"device: "Google/Pixel 6 Pro" }"#,
+            r#"engine: Engine { name: "Blink", version: "112.0.0.0" }, "#,
+            r#"os: Some(Os { name: "Android", version: "13", exact: true }), "#,
+            r#"device: Device { family: "Pixel 6 Pro", brand: "Google", model: "Pixel 6 Pro", manufacturer: "Google", marketing_name: "Pixel 6 Pro" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
 
@@ -781,8 +1289,14 @@ mod test_more_02 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile", version: "112.0.0.0", "#,
-            r#"os: Some(Os { name: "Android", version: "13" }), "#,
-            r#"device: "Google/Pixel 6a" }"#,
+            r#"engine: Engine { name: "Blink", version: "112.0.0.0" }, "#,
+            r#"os: Some(Os { name: "Android", version: "13", exact: true }), "#,
+            r#"device: Device { family: "Pixel 6a", brand: "Google", model: "Pixel 6a", manufacturer: "Google", marketing_name: "Pixel 6a" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
 
@@ -791,14 +1305,21 @@ mod test_more_02 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile", version: "112.0.0.0", "#,
-            r#"os: Some(Os { name: "Android", version: "13" }), "#,
-            r#"device: "Google/Pixel 6" }"#,
+            r#"engine: Engine { name: "Blink", version: "112.0.0.0" }, "#,
+            r#"os: Some(Os { name: "Android", version: "13", exact: true }), "#,
+            r#"device: Device { family: "Pixel 6", brand: "Google", model: "Pixel 6", manufacturer: "Google", marketing_name: "Pixel 6" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
     }
 }
 
-#[cfg(test)]
+// the expected values are of the full parser
+#[cfg(all(test, feature = "extractor"))]
 mod test_more_03 {
     use super::*;
     #[test]
@@ -808,8 +1329,14 @@ mod test_more_03 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile WebView", version: "132.0.6834.163", "#,
-            r#"os: Some(Os { name: "Android", version: "15" }), "#,
-            r#"device: "Motorola/g - 2025" }"#,
+            r#"engine: Engine { name: "Blink", version: "132.0.6834.163" }, "#,
+            r#"os: Some(Os { name: "Android", version: "15", exact: true }), "#,
+            r#"device: Device { family: "Motorola g - 2025", brand: "Motorola", model: "g - 2025", manufacturer: "", marketing_name: "" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: AndroidWebView, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
         //break_dump_extractor!(s0);
@@ -819,8 +1346,14 @@ mod test_more_03 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Android", version: "15", "#,
-            r#"os: Some(Os { name: "Android", version: "15" }), "#,
-            r#"device: "Motorola/edge 30 neo" }"#,
+            r#"engine: Engine { name: "", version: "" }, "#,
+            r#"os: Some(Os { name: "Android", version: "15", exact: true }), "#,
+            r#"device: Device { family: "Motorola edge 30 neo", brand: "Motorola", model: "edge 30 neo", manufacturer: "", marketing_name: "" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
     }
@@ -831,8 +1364,14 @@ mod test_more_03 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Instabridge", version: "22", "#,
-            r#"os: Some(Os { name: "Android", version: "14" }), "#,
-            r#"device: "Generic_Android/Moto g04" }"#,
+            r#"engine: Engine { name: "Blink", version: "122.0.6261.64" }, "#,
+            r#"os: Some(Os { name: "Android", version: "14", exact: true }), "#,
+            r#"device: Device { family: "Moto g04", brand: "Generic_Android", model: "Moto g04", manufacturer: "", marketing_name: "" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
 
@@ -842,8 +1381,14 @@ mod test_more_03 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Android", version: "14", "#,
-            r#"os: Some(Os { name: "Android", version: "14" }), "#,
-            r#"device: "Motorola/g stylus 5G - 2024" }"#,
+            r#"engine: Engine { name: "", version: "" }, "#,
+            r#"os: Some(Os { name: "Android", version: "14", exact: true }), "#,
+            r#"device: Device { family: "Motorola g stylus 5G - 2024", brand: "Motorola", model: "g stylus 5G - 2024", manufacturer: "", marketing_name: "" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: AndroidWebView, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
 
@@ -852,8 +1397,14 @@ mod test_more_03 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile WebView", version: "123.0.6312.99", "#,
-            r#"os: Some(Os { name: "Android", version: "14" }), "#,
-            r#"device: "Motorola/g power 5G - 2024" }"#,
+            r#"engine: Engine { name: "Blink", version: "123.0.6312.99" }, "#,
+            r#"os: Some(Os { name: "Android", version: "14", exact: true }), "#,
+            r#"device: Device { family: "Motorola g power 5G - 2024", brand: "Motorola", model: "g power 5G - 2024", manufacturer: "", marketing_name: "" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: AndroidWebView, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
 
@@ -862,8 +1413,14 @@ mod test_more_03 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile WebView", version: "126.0.6478.134", "#,
-            r#"os: Some(Os { name: "Android", version: "14" }), "#,
-            r#"device: "Motorola/rola razr 50 ultra" }"#,
+            r#"engine: Engine { name: "Blink", version: "126.0.6478.134" }, "#,
+            r#"os: Some(Os { name: "Android", version: "14", exact: true }), "#,
+            r#"device: Device { family: "Motorolarola razr 50 ultra", brand: "Motorola", model: "rola razr 50 ultra", manufacturer: "", marketing_name: "" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: AndroidWebView, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
     }
@@ -874,8 +1431,14 @@ mod test_more_03 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile", version: "112.0.0.0", "#,
-            r#"os: Some(Os { name: "Android", version: "12" }), "#,
-            r#"device: "Motorola/g pure" }"#,
+            r#"engine: Engine { name: "Blink", version: "112.0.0.0" }, "#,
+            r#"os: Some(Os { name: "Android", version: "12", exact: true }), "#,
+            r#"device: Device { family: "Motorola g pure", brand: "Motorola", model: "g pure", manufacturer: "", marketing_name: "" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
 
@@ -884,8 +1447,14 @@ mod test_more_03 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile", version: "112.0.0.0", "#,
-            r#"os: Some(Os { name: "Android", version: "12" }), "#,
-            r#"device: "Motorola/g stylus 5G" }"#,
+            r#"engine: Engine { name: "Blink", version: "112.0.0.0" }, "#,
+            r#"os: Some(Os { name: "Android", version: "12", exact: true }), "#,
+            r#"device: Device { family: "Motorola g stylus 5G", brand: "Motorola", model: "g stylus 5G", manufacturer: "", marketing_name: "" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
 
@@ -894,8 +1463,14 @@ mod test_more_03 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile", version: "112.0.0.0", "#,
-            r#"os: Some(Os { name: "Android", version: "12" }), "#,
-            r#"device: "Motorola/g stylus 5G (2022)" }"#,
+            r#"engine: Engine { name: "Blink", version: "112.0.0.0" }, "#,
+            r#"os: Some(Os { name: "Android", version: "12", exact: true }), "#,
+            r#"device: Device { family: "Motorola g stylus 5G (2022)", brand: "Motorola", model: "g stylus 5G (2022)", manufacturer: "", marketing_name: "" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
 
@@ -904,8 +1479,14 @@ mod test_more_03 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile", version: "112.0.0.0", "#,
-            r#"os: Some(Os { name: "Android", version: "12" }), "#,
-            r#"device: "Motorola/g 5G (2022)" }"#,
+            r#"engine: Engine { name: "Blink", version: "112.0.0.0" }, "#,
+            r#"os: Some(Os { name: "Android", version: "12", exact: true }), "#,
+            r#"device: Device { family: "Motorola g 5G (2022)", brand: "Motorola", model: "g 5G (2022)", manufacturer: "", marketing_name: "" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
 
@@ -914,8 +1495,14 @@ mod test_more_03 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile", version: "112.0.0.0", "#,
-            r#"os: Some(Os { name: "Android", version: "12" }), "#,
-            r#"device: "Motorola/g power (2022)" }"#,
+            r#"engine: Engine { name: "Blink", version: "112.0.0.0" }, "#,
+            r#"os: Some(Os { name: "Android", version: "12", exact: true }), "#,
+            r#"device: Device { family: "Motorola g power (2022)", brand: "Motorola", model: "g power (2022)", manufacturer: "", marketing_name: "" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
 
@@ -924,14 +1511,21 @@ mod test_more_03 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile", version: "112.0.0.0", "#,
-            r#"os: Some(Os { name: "Android", version: "11" }), "#,
-            r#"device: "Motorola/g power (2021)" }"#,
+            r#"engine: Engine { name: "Blink", version: "112.0.0.0" }, "#,
+            r#"os: Some(Os { name: "Android", version: "11", exact: true }), "#,
+            r#"device: Device { family: "Motorola g power (2021)", brand: "Motorola", model: "g power (2021)", manufacturer: "", marketing_name: "" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
     }
 }
 
-#[cfg(test)]
+// the expected values are of the full parser
+#[cfg(all(test, feature = "extractor"))]
 mod test_more_04 {
     use super::*;
     #[test]
@@ -941,8 +1535,14 @@ mod test_more_04 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile WebView", version: "116.0.0.0", "#,
-            r#"os: Some(Os { name: "Android", version: "13" }), "#,
-            r#"device: "Generic_Android/23129RAA4G" }"#,
+            r#"engine: Engine { name: "Blink", version: "116.0.0.0" }, "#,
+            r#"os: Some(Os { name: "Android", version: "13", exact: true }), "#,
+            r#"device: Device { family: "23129RAA4G", brand: "Generic_Android", model: "23129RAA4G", manufacturer: "", marketing_name: "" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: AndroidWebView, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
 
@@ -951,8 +1551,14 @@ mod test_more_04 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile WebView", version: "130.0.6723.86", "#,
-            r#"os: Some(Os { name: "Android", version: "15" }), "#,
-            r#"device: "Generic_Android/24129RT7CC" }"#,
+            r#"engine: Engine { name: "Blink", version: "130.0.6723.86" }, "#,
+            r#"os: Some(Os { name: "Android", version: "15", exact: true }), "#,
+            r#"device: Device { family: "24129RT7CC", brand: "Generic_Android", model: "24129RT7CC", manufacturer: "", marketing_name: "" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: AndroidWebView, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
     }
@@ -963,8 +1569,14 @@ mod test_more_04 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile WebView", version: "99.0.4844.88", "#,
-            r#"os: Some(Os { name: "Android", version: "12" }), "#,
-            r#"device: "Huawei/HBP-LX9" }"#,
+            r#"engine: Engine { name: "Blink", version: "99.0.4844.88" }, "#,
+            r#"os: Some(Os { name: "Android", version: "12", exact: true }), "#,
+            r#"device: Device { family: "HBP-LX9", brand: "Huawei", model: "HBP-LX9", manufacturer: "", marketing_name: "" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: AndroidWebView, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
 
@@ -975,8 +1587,14 @@ mod test_more_04 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile WebView", version: "100.0.4896.58", "#,
-            r#"os: Some(Os { name: "Android", version: "12" }), "#,
-            r#"device: "Huawei/ADA-AL00" }"#,
+            r#"engine: Engine { name: "Blink", version: "100.0.4896.58" }, "#,
+            r#"os: Some(Os { name: "Android", version: "12", exact: true }), "#,
+            r#"device: Device { family: "ADA-AL00", brand: "Huawei", model: "ADA-AL00", manufacturer: "", marketing_name: "" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
 
@@ -987,8 +1605,14 @@ mod test_more_04 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile WebView", version: "99.0.4844.88", "#,
-            r#"os: Some(Os { name: "Android", version: "12" }), "#,
-            r#"device: "Huawei/PSD-AL00" }"#,
+            r#"engine: Engine { name: "Blink", version: "99.0.4844.88" }, "#,
+            r#"os: Some(Os { name: "Android", version: "12", exact: true }), "#,
+            r#"device: Device { family: "PSD-AL00", brand: "Huawei", model: "PSD-AL00", manufacturer: "", marketing_name: "" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: AndroidWebView, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
     }
@@ -999,8 +1623,14 @@ mod test_more_04 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile WebView", version: "122.0.6261.119", "#,
-            r#"os: Some(Os { name: "Android", version: "14" }), "#,
-            r#"device: "Generic_Android/24030PN60G" }"#,
+            r#"engine: Engine { name: "Blink", version: "122.0.6261.119" }, "#,
+            r#"os: Some(Os { name: "Android", version: "14", exact: true }), "#,
+            r#"device: Device { family: "24030PN60G", brand: "Generic_Android", model: "24030PN60G", manufacturer: "", marketing_name: "" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: AndroidWebView, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
 
@@ -1009,8 +1639,14 @@ mod test_more_04 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile WebView", version: "120.0.6099.193", "#,
-            r#"os: Some(Os { name: "Android", version: "14" }), "#,
-            r#"device: "Generic_Android/2405CPX3DC" }"#,
+            r#"engine: Engine { name: "Blink", version: "120.0.6099.193" }, "#,
+            r#"os: Some(Os { name: "Android", version: "14", exact: true }), "#,
+            r#"device: Device { family: "2405CPX3DC", brand: "Generic_Android", model: "2405CPX3DC", manufacturer: "", marketing_name: "" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: AndroidWebView, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
     }
@@ -1021,8 +1657,14 @@ mod test_more_04 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile", version: "112.0.0.0", "#,
-            r#"os: Some(Os { name: "Android", version: "12" }), "#,
-            r#"device: "XiaoMi/Redmi Note 9 Pro" }"#,
+            r#"engine: Engine { name: "Blink", version: "112.0.0.0" }, "#,
+            r#"os: Some(Os { name: "Android", version: "12", exact: true }), "#,
+            r#"device: Device { family: "XiaoMi Redmi Note 9 Pro", brand: "XiaoMi", model: "Redmi Note 9 Pro", manufacturer: "", marketing_name: "" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
 
@@ -1031,8 +1673,14 @@ mod test_more_04 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile", version: "112.0.0.0", "#,
-            r#"os: Some(Os { name: "Android", version: "11" }), "#,
-            r#"device: "XiaoMi/Redmi Note 8 Pro" }"#,
+            r#"engine: Engine { name: "Blink", version: "112.0.0.0" }, "#,
+            r#"os: Some(Os { name: "Android", version: "11", exact: true }), "#,
+            r#"device: Device { family: "XiaoMi Redmi Note 8 Pro", brand: "XiaoMi", model: "Redmi Note 8 Pro", manufacturer: "", marketing_name: "" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
     }
@@ -1043,8 +1691,14 @@ mod test_more_04 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile", version: "112.0.0.0", "#,
-            r#"os: Some(Os { name: "Android", version: "10" }), "#,
-            r#"device: "Huawei/VOG-L29" }"#,
+            r#"engine: Engine { name: "Blink", version: "112.0.0.0" }, "#,
+            r#"os: Some(Os { name: "Android", version: "10", exact: true }), "#,
+            r#"device: Device { family: "Huawei VOG-L29", brand: "Huawei", model: "VOG-L29", manufacturer: "Huawei", marketing_name: "P30 Pro" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
 
@@ -1053,8 +1707,14 @@ mod test_more_04 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile", version: "112.0.0.0", "#,
-            r#"os: Some(Os { name: "Android", version: "10" }), "#,
-            r#"device: "Generic_Android/MAR-LX1A" }"#,
+            r#"engine: Engine { name: "Blink", version: "112.0.0.0" }, "#,
+            r#"os: Some(Os { name: "Android", version: "10", exact: true }), "#,
+            r#"device: Device { family: "MAR-LX1A", brand: "Generic_Android", model: "MAR-LX1A", manufacturer: "Huawei", marketing_name: "P30 lite" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
     }
@@ -1065,8 +1725,14 @@ mod test_more_04 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile", version: "112.0.0.0", "#,
-            r#"os: Some(Os { name: "Android", version: "13" }), "#,
-            r#"device: "Generic_Android/M2101K6G" }"#,
+            r#"engine: Engine { name: "Blink", version: "112.0.0.0" }, "#,
+            r#"os: Some(Os { name: "Android", version: "13", exact: true }), "#,
+            r#"device: Device { family: "M2101K6G", brand: "Generic_Android", model: "M2101K6G", manufacturer: "Xiaomi", marketing_name: "Redmi Note 10 Pro" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
 
@@ -1075,8 +1741,14 @@ mod test_more_04 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile", version: "112.0.0.0", "#,
-            r#"os: Some(Os { name: "Android", version: "12" }), "#,
-            r#"device: "Generic_Android/M2102J20SG" }"#,
+            r#"engine: Engine { name: "Blink", version: "112.0.0.0" }, "#,
+            r#"os: Some(Os { name: "Android", version: "12", exact: true }), "#,
+            r#"device: Device { family: "M2102J20SG", brand: "Generic_Android", model: "M2102J20SG", manufacturer: "Xiaomi", marketing_name: "POCO X3 Pro" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
 
@@ -1087,8 +1759,14 @@ mod test_more_04 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile", version: "112.0.0.0", "#,
-            r#"os: Some(Os { name: "Android", version: "12" }), "#,
-            r#"device: "Generic_Android/2201116SG" }"#,
+            r#"engine: Engine { name: "Blink", version: "112.0.0.0" }, "#,
+            r#"os: Some(Os { name: "Android", version: "12", exact: true }), "#,
+            r#"device: Device { family: "2201116SG", brand: "Generic_Android", model: "2201116SG", manufacturer: "", marketing_name: "" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
 
@@ -1099,14 +1777,21 @@ mod test_more_04 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Chrome Mobile", version: "112.0.0.0", "#,
-            r#"os: Some(Os { name: "Android", version: "12" }), "#,
-            r#"device: "OnePlus/OnePlus DE2118" }"#,
+            r#"engine: Engine { name: "Blink", version: "112.0.0.0" }, "#,
+            r#"os: Some(Os { name: "Android", version: "12", exact: true }), "#,
+            r#"device: Device { family: "OnePlus DE2118", brand: "OnePlus", model: "OnePlus DE2118", manufacturer: "", marketing_name: "" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Unknown, "#,
+            r#"runtime: BrowserTab, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
     }
 }
 
-#[cfg(test)]
+// the expected values are of the full parser
+#[cfg(all(test, feature = "extractor"))]
 mod test_more_05 {
     use super::*;
     #[test]
@@ -1116,8 +1801,14 @@ mod test_more_05 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Mobile Safari UI/WKWebView", version: "", "#,
-            r#"os: Some(Os { name: "iOS", version: "18.3.2" }), "#,
-            r#"device: "Apple/iPhone17,5" }"#,
+            r#"engine: Engine { name: "WebKit", version: "605.1.15" }, "#,
+            r#"os: Some(Os { name: "iOS", version: "18.3.2", exact: true }), "#,
+            r#"device: Device { family: "iPhone", brand: "Apple", model: "iPhone17,5", manufacturer: "", marketing_name: "" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Arm64, "#,
+            r#"runtime: WkWebView, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
 
@@ -1126,8 +1817,14 @@ mod test_more_05 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Mobile Safari UI/WKWebView", version: "", "#,
-            r#"os: Some(Os { name: "iOS", version: "18.2.1" }), "#,
-            r#"device: "Apple/iPhone17,1" }"#,
+            r#"engine: Engine { name: "WebKit", version: "605.1.15" }, "#,
+            r#"os: Some(Os { name: "iOS", version: "18.2.1", exact: true }), "#,
+            r#"device: Device { family: "iPhone", brand: "Apple", model: "iPhone17,1", manufacturer: "", marketing_name: "" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Arm64, "#,
+            r#"runtime: WkWebView, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
 
@@ -1136,8 +1833,14 @@ mod test_more_05 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Mobile Safari UI/WKWebView", version: "", "#,
-            r#"os: Some(Os { name: "iOS", version: "18.3.1" }), "#,
-            r#"device: "Apple/iPhone17,2" }"#,
+            r#"engine: Engine { name: "WebKit", version: "605.1.15" }, "#,
+            r#"os: Some(Os { name: "iOS", version: "18.3.1", exact: true }), "#,
+            r#"device: Device { family: "iPhone", brand: "Apple", model: "iPhone17,2", manufacturer: "", marketing_name: "" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Arm64, "#,
+            r#"runtime: WkWebView, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
 
@@ -1146,8 +1849,14 @@ mod test_more_05 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Mobile Safari UI/WKWebView", version: "", "#,
-            r#"os: Some(Os { name: "iOS", version: "18.3.2" }), "#,
-            r#"device: "Apple/iPhone17,3" }"#,
+            r#"engine: Engine { name: "WebKit", version: "605.1.15" }, "#,
+            r#"os: Some(Os { name: "iOS", version: "18.3.2", exact: true }), "#,
+            r#"device: Device { family: "iPhone", brand: "Apple", model: "iPhone17,3", manufacturer: "", marketing_name: "" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Arm64, "#,
+            r#"runtime: WkWebView, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
 
@@ -1156,8 +1865,14 @@ mod test_more_05 {
         let browser = convert_from_user_agent(s0).unwrap();
         let target = concat!(
             r#"Browser { name: "Mobile Safari UI/WKWebView", version: "", "#,
-            r#"os: Some(Os { name: "iOS", version: "18.2.1" }), "#,
-            r#"device: "Apple/iPhone17,4" }"#,
+            r#"engine: Engine { name: "WebKit", version: "605.1.15" }, "#,
+            r#"os: Some(Os { name: "iOS", version: "18.2.1", exact: true }), "#,
+            r#"device: Device { family: "iPhone", brand: "Apple", model: "iPhone17,4", manufacturer: "", marketing_name: "" }, "#,
+            r#"device_class: Mobile, "#,
+            r#"architecture: Arm64, "#,
+            r#"runtime: WkWebView, "#,
+            r#"in_app: None, "#,
+            r#"client: Client { kind: Browser, name: "", vendor: "" } }"#,
         );
         assert_eq!(format!("{browser:?}"), target);
     }
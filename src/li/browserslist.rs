@@ -0,0 +1,498 @@
+use super::{Browser, Error, Result, Version};
+use std::collections::BTreeSet;
+
+// The snapshot of the browser versions and their global usage,
+// that is converted from `resources/browserslist.yaml` by `build.rs`.
+pub(crate) struct Agent {
+    id: &'static str,
+    names: &'static [&'static str],
+    dead: bool,
+    // from the oldest, as `("18.5-18.6", 4.62)`
+    versions: &'static [(&'static str, f64)],
+}
+
+include!(concat!(env!("OUT_DIR"), "/browserslist.rs"));
+
+const DEFAULTS: &str = "> 0.5%, last 2 versions, Firefox ESR, not dead";
+
+// the browser family and the agent of `browserslist`.
+// all browsers on iOS are `ios_saf`, as they are of `WebKit`.
+const FAMILIES: &[(&str, &str)] = &[
+    ("Chrome", "chrome"),
+    ("Chromium", "chrome"),
+    ("HeadlessChrome", "chrome"),
+    ("Chrome Mobile", "and_chr"),
+    ("Chrome Mobile WebView", "android"),
+    ("Edge", "edge"),
+    ("Firefox", "firefox"),
+    ("Firefox Mobile", "and_ff"),
+    ("Safari", "safari"),
+    ("Opera", "opera"),
+    ("Opera Mobile", "op_mob"),
+    ("Opera Mini", "op_mini"),
+    ("IE", "ie"),
+    ("Samsung Internet", "samsung"),
+    ("UC Browser", "and_uc"),
+];
+
+/// The browsers of the `browserslist` queries, as `defaults` or `last 2 Chrome versions`.
+///
+/// The queries are evaluated with the embedded snapshot of the browser versions
+/// and their global usage, without the network. See [`Browserslist::snapshot_date()`].
+///
+/// The supported queries are `defaults`, `dead`, `Firefox ESR`,
+/// `last 2 versions`, `last 2 major versions`, `last 2 Chrome versions`,
+/// `> 0.5%` (and `>=`, `<`, `<=`), `Safari >= 16.4` (and `>`, `<`, `<=`),
+/// `Safari 16.4` and `Chrome 100-110`.
+/// They are combined with `,` or `or`, `and`, and `not`.
+///
+/// # Examples
+/// ```rust
+/// use browserinfo::{Browser, Browserslist};
+///
+/// # fn func(browser: &Browser) -> anyhow::Result<()> {
+/// let modern = Browserslist::parse("defaults, not ie 11")?;
+/// if !modern.contains(browser) {
+///     // load the fallback bundle
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Browserslist {
+    // the index of `AGENTS`, and the index of its versions
+    targets: BTreeSet<(usize, usize)>,
+}
+
+impl Browserslist {
+    /// Evaluates the queries.
+    pub fn parse(query: &str) -> Result<Self> {
+        let targets = evaluate(query)?;
+        Ok(Self { targets })
+    }
+    /// Returns the date of the embedded snapshot, as `2026-01-01`.
+    pub fn snapshot_date() -> &'static str {
+        SNAPSHOT_DATE
+    }
+    /// Returns true if the browser is one of the queries.
+    ///
+    /// The browser that is newer than the snapshot is taken as the latest version.
+    /// The browser that is not of `browserslist`, as a crawler, is not.
+    pub fn contains(&self, browser: &Browser) -> bool {
+        match find_target(browser) {
+            Some(target) => self.targets.contains(&target),
+            None => false,
+        }
+    }
+    /// Returns the browsers, as `chrome 142` or `ios_saf 18.5-18.6`.
+    pub fn targets(&self) -> Vec<String> {
+        self.targets
+            .iter()
+            .rev()
+            .map(|&(a, v)| format!("{} {}", AGENTS[a].id, AGENTS[a].versions[v].0))
+            .collect()
+    }
+}
+
+impl std::str::FromStr for Browserslist {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse(s)
+    }
+}
+
+impl Browser {
+    /// Returns true if this is one of the `browserslist` queries, as `defaults`.
+    ///
+    /// See [`Browserslist`].
+    pub fn in_browserslist(&self, query: &str) -> Result<bool> {
+        Ok(Browserslist::parse(query)?.contains(self))
+    }
+}
+
+type Targets = BTreeSet<(usize, usize)>;
+
+fn evaluate(query: &str) -> Result<Targets> {
+    let mut targets = Targets::new();
+    for (i, (and, q)) in split_queries(query).into_iter().enumerate() {
+        let (not, q) = match strip_prefix_ci(q, "not ") {
+            Some(q) => (true, q.trim()),
+            None => (false, q),
+        };
+        if i == 0 && not {
+            return Err(invalid(query, "write any query before `not`"));
+        }
+        let found = select(q).ok_or_else(|| invalid(query, q))?;
+        if not {
+            targets.retain(|a| !found.contains(a));
+        } else if and {
+            targets.retain(|a| found.contains(a));
+        } else {
+            targets.extend(found);
+        }
+    }
+    Ok(targets)
+}
+
+// the queries, and true if it is combined with `and`
+fn split_queries(query: &str) -> Vec<(bool, &str)> {
+    let mut queries = Vec::new();
+    for part in query.split(',') {
+        let mut and = false;
+        let mut rest = part;
+        loop {
+            // the ascii lowercase has the same byte offsets
+            let lower = rest.to_ascii_lowercase();
+            let next = [(" or ", false), (" and ", true)]
+                .into_iter()
+                .filter_map(|(sep, is_and)| lower.find(sep).map(|idx| (idx, sep.len(), is_and)))
+                .min();
+            let Some((idx, len, is_and)) = next else {
+                queries.push((and, rest.trim()));
+                break;
+            };
+            queries.push((and, rest[..idx].trim()));
+            and = is_and;
+            rest = &rest[idx + len..];
+        }
+    }
+    queries
+}
+
+fn select(query: &str) -> Option<Targets> {
+    let q = query.split_whitespace().collect::<Vec<_>>().join(" ");
+    let lower = q.to_ascii_lowercase();
+    match lower.as_str() {
+        "defaults" => return evaluate(DEFAULTS).ok(),
+        "dead" => return Some(select_agents(|a| a.dead)),
+        "firefox esr" | "ff esr" | "fx esr" => {
+            let idx = find_agent("firefox")?;
+            let versions = AGENTS[idx].versions.iter().enumerate();
+            return Some(
+                versions
+                    .filter(|(_, (v, _))| FIREFOX_ESR.contains(v))
+                    .map(|(i, _)| (idx, i))
+                    .collect(),
+            );
+        }
+        _ => (),
+    }
+    if let Some(rest) = lower.strip_prefix("last ") {
+        return select_last(rest);
+    }
+    if let Some(targets) = select_usage(&lower) {
+        return Some(targets);
+    }
+    select_versions(&lower)
+}
+
+fn select_agents(f: impl Fn(&Agent) -> bool) -> Targets {
+    let mut targets = Targets::new();
+    for (a, agent) in AGENTS.iter().enumerate() {
+        if f(agent) {
+            targets.extend((0..agent.versions.len()).map(|v| (a, v)));
+        }
+    }
+    targets
+}
+
+// `2 versions`, `2 major versions` or `2 chrome versions`
+fn select_last(rest: &str) -> Option<Targets> {
+    let (n, rest) = rest.split_once(' ')?;
+    let n: usize = n.parse().ok()?;
+    let rest = rest
+        .strip_suffix(" versions")
+        .or_else(|| rest.strip_suffix(" version"))
+        .or_else(|| matches!(rest, "versions" | "version").then_some(""))?;
+    let (agents, major): (Vec<usize>, bool) = match rest {
+        "" => ((0..AGENTS.len()).collect(), false),
+        "major" => ((0..AGENTS.len()).collect(), true),
+        name => match name.strip_suffix(" major") {
+            Some(name) => (vec![find_agent(name)?], true),
+            None => (vec![find_agent(name)?], false),
+        },
+    };
+    let mut targets = Targets::new();
+    for a in agents {
+        let versions = AGENTS[a].versions;
+        let skip = if major {
+            // the versions of the last `n` majors
+            let mut majors: Vec<u32> = versions.iter().filter_map(|(v, _)| major_of(v)).collect();
+            majors.dedup();
+            let min = majors.iter().rev().take(n).next_back().copied();
+            versions
+                .iter()
+                .position(|(v, _)| major_of(v) >= min)
+                .unwrap_or(versions.len())
+        } else {
+            versions.len().saturating_sub(n)
+        };
+        targets.extend((skip..versions.len()).map(|v| (a, v)));
+    }
+    Some(targets)
+}
+
+// `> 0.5%`
+fn select_usage(q: &str) -> Option<Targets> {
+    let (op, rest) = [">=", "<=", ">", "<"]
+        .into_iter()
+        .find_map(|op| q.strip_prefix(op).map(|rest| (op, rest)))?;
+    let rest = rest.trim().strip_suffix('%')?;
+    let percent: f64 = rest.trim().parse().ok()?;
+    let test = |usage: f64| match op {
+        ">=" => usage >= percent,
+        "<=" => usage <= percent,
+        ">" => usage > percent,
+        _ => usage < percent,
+    };
+    let mut targets = Targets::new();
+    for (a, agent) in AGENTS.iter().enumerate() {
+        for (v, (_, usage)) in agent.versions.iter().enumerate() {
+            if test(*usage) {
+                targets.insert((a, v));
+            }
+        }
+    }
+    Some(targets)
+}
+
+// `safari >= 16.4`, `safari 16.4` or `chrome 100-110`
+fn select_versions(q: &str) -> Option<Targets> {
+    let (name, cond) = q.rsplit_once(' ')?;
+    let (name, op, version) = match [">=", "<=", ">", "<"]
+        .into_iter()
+        .find(|op| name.ends_with(&format!(" {op}")) || name == *op)
+    {
+        Some(op) => (name.strip_suffix(op)?.trim(), op, cond),
+        None => (name, "", cond),
+    };
+    let a = find_agent(name)?;
+    let versions = AGENTS[a].versions.iter().enumerate();
+    let matched: Vec<usize> = if op.is_empty() {
+        match version.split_once('-') {
+            Some((from, to)) if !is_range_entry(a, version) => {
+                let (from, to) = (Version::parse(from), Version::parse(to));
+                if from.is_empty() || to.is_empty() {
+                    return None;
+                }
+                versions
+                    .filter(|(_, (v, _))| {
+                        let v = first_version(v);
+                        v.truncate(from.parts().len()) >= from && v.truncate(to.parts().len()) <= to
+                    })
+                    .map(|(i, _)| i)
+                    .collect()
+            }
+            _ => {
+                let v = Version::parse(version);
+                if v.is_empty() && version != "all" {
+                    return None;
+                }
+                versions
+                    .filter(|(_, (entry, _))| *entry == version || entry_contains(entry, &v))
+                    .map(|(i, _)| i)
+                    .collect()
+            }
+        }
+    } else {
+        let required = Version::parse(version);
+        if required.is_empty() {
+            return None;
+        }
+        versions
+            .filter(|(_, (v, _))| {
+                let v = first_version(v).truncate(required.parts().len());
+                match op {
+                    ">=" => v >= required,
+                    "<=" => v <= required,
+                    ">" => v > required,
+                    _ => v < required,
+                }
+            })
+            .map(|(i, _)| i)
+            .collect()
+    };
+    Some(matched.into_iter().map(|v| (a, v)).collect())
+}
+
+fn find_agent(name: &str) -> Option<usize> {
+    AGENTS.iter().position(|a| {
+        a.id.eq_ignore_ascii_case(name) || a.names.iter().any(|n| n.eq_ignore_ascii_case(name))
+    })
+}
+
+fn is_range_entry(a: usize, version: &str) -> bool {
+    AGENTS[a].versions.iter().any(|(v, _)| *v == version)
+}
+
+fn major_of(v: &str) -> Option<u32> {
+    first_version(v).major
+}
+
+// the first version of the range, as `18.5` of `18.5-18.6`
+fn first_version(v: &str) -> Version {
+    Version::parse(v.split('-').next().unwrap_or_default())
+}
+
+// the version is the entry, as `18.5.1` is in `18.5-18.6`
+fn entry_contains(entry: &str, version: &Version) -> bool {
+    if entry == "all" {
+        return true;
+    }
+    if version.is_empty() {
+        return false;
+    }
+    let (from, to) = match entry.split_once('-') {
+        Some((from, to)) => (Version::parse(from), Version::parse(to)),
+        None => (Version::parse(entry), Version::parse(entry)),
+    };
+    version.truncate(from.parts().len()) >= from && version.truncate(to.parts().len()) <= to
+}
+
+fn find_target(browser: &Browser) -> Option<(usize, usize)> {
+    if browser.name.is_empty() {
+        return None;
+    }
+    let os = browser.os.as_ref();
+    let (id, version) = match os.filter(|a| a.name == "iOS" || a.name == "iPadOS") {
+        Some(os) => ("ios_saf", &os.version),
+        None => {
+            let (_, id) = FAMILIES.iter().find(|(name, _)| *name == browser.name)?;
+            (*id, &browser.version)
+        }
+    };
+    let a = find_agent(id)?;
+    let versions = AGENTS[a].versions;
+    if let Some(v) = versions
+        .iter()
+        .position(|(entry, _)| entry_contains(entry, version))
+    {
+        return Some((a, v));
+    }
+    // newer than the snapshot
+    let (latest, _) = versions.last()?;
+    let latest = first_version(latest);
+    (!version.is_empty() && version.truncate(latest.parts().len()) > latest)
+        .then_some((a, versions.len() - 1))
+}
+
+fn strip_prefix_ci<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+    let head = s.get(..prefix.len())?;
+    head.eq_ignore_ascii_case(prefix)
+        .then(|| &s[prefix.len()..])
+}
+
+fn invalid(query: &str, reason: &str) -> Error {
+    Error::Validation(format!("browserslist: {query:?}: {reason}"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::Os;
+    use super::*;
+    fn browser(name: &str, version: &str, os: &str, os_version: &str) -> Browser {
+        Browser {
+            name: name.to_string(),
+            version: Version::parse(version),
+            os: Some(Os {
+                name: os.to_string(),
+                version: Version::parse(os_version),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+    // the header and `source` of the snapshot are the ones of `scripts/browserslist.js`,
+    // or the snapshot does not say it is generated.
+    #[test]
+    fn test_snapshot_header() {
+        let yaml = include_str!("../../resources/browserslist.yaml");
+        let script = include_str!("../../scripts/browserslist.js");
+        let makefile = include_str!("../../Makefile");
+        let header: Vec<&str> = yaml.lines().take_while(|a| a.starts_with('#')).collect();
+        let source = yaml
+            .lines()
+            .find_map(|a| a.strip_prefix("source: "))
+            .unwrap();
+        match source.strip_prefix("'caniuse-lite ") {
+            Some(version) => {
+                let script_header: Vec<&str> = script
+                    .lines()
+                    .filter_map(|a| a.trim().strip_prefix('"')?.strip_suffix("\","))
+                    .filter(|a| a.starts_with('#'))
+                    .collect();
+                assert_eq!(header, script_header);
+                let pinned = makefile
+                    .lines()
+                    .find_map(|a| a.strip_prefix("CANIUSE_LITE = "))
+                    .unwrap();
+                assert_eq!(version.strip_suffix('\''), Some(pinned));
+            }
+            None => {
+                assert_eq!(source, "'hand-written'");
+                assert!(header.iter().all(|a| !a.contains("@generated")));
+            }
+        }
+    }
+    #[test]
+    fn test_defaults() {
+        let list = Browserslist::parse("defaults").unwrap();
+        assert!(list.contains(&browser("Chrome", "142.0.0.0", "Windows", "10")));
+        assert!(list.contains(&browser("Chrome Mobile", "142.0.7444.102", "Android", "14")));
+        assert!(list.contains(&browser("Firefox", "140.5", "Linux", "")));
+        assert!(list.contains(&browser("Mobile Safari", "18.6", "iOS", "18.6.2")));
+        assert!(list.contains(&browser("Chrome Mobile iOS", "126.0", "iOS", "18.5")));
+        assert!(!list.contains(&browser("Chrome", "109.0.5414.120", "Windows", "7")));
+        assert!(!list.contains(&browser("IE", "11.0", "Windows", "10")));
+        assert!(!list.contains(&browser("Mobile Safari", "12.1", "iOS", "12.5.7")));
+        assert!(!list.contains(&browser("Googlebot", "2.1", "", "")));
+        // newer than the snapshot
+        assert!(list.contains(&browser("Chrome", "150.0.0.0", "Windows", "10")));
+    }
+    #[test]
+    fn test_last() {
+        let list = Browserslist::parse("last 2 Chrome versions").unwrap();
+        assert_eq!(list.targets(), vec!["chrome 143", "chrome 142"]);
+        let list = Browserslist::parse("last 1 version").unwrap();
+        assert!(list.targets().contains(&"ios_saf 26.2".to_string()));
+        assert!(list.targets().contains(&"ie 11".to_string()));
+        let list = Browserslist::parse("last 1 safari major version").unwrap();
+        assert_eq!(
+            list.targets(),
+            vec!["safari 26.2", "safari 26.1", "safari 26.0"]
+        );
+    }
+    #[test]
+    fn test_combine() {
+        let list = Browserslist::parse("last 2 versions, not dead").unwrap();
+        assert!(!list.targets().iter().any(|a| a.starts_with("ie ")));
+        let list = Browserslist::parse("chrome >= 140 and > 2%").unwrap();
+        assert_eq!(list.targets(), vec!["chrome 142", "chrome 141"]);
+        let list = Browserslist::parse("Safari 18.5 or ios_saf 17.6-18.0 or ie 11").unwrap();
+        assert_eq!(
+            list.targets(),
+            vec![
+                "ios_saf 18.0",
+                "ios_saf 17.6-17.7",
+                "ie 11",
+                "safari 18.5-18.6"
+            ]
+        );
+        let list = Browserslist::parse("Firefox ESR").unwrap();
+        assert_eq!(list.targets(), vec!["firefox 140", "firefox 115"]);
+    }
+    #[test]
+    fn test_invalid() {
+        for q in [
+            "",
+            "not dead",
+            "last two versions",
+            "> 0.5",
+            "Netscape >= 4",
+            "defaults,",
+        ] {
+            let e = Browserslist::parse(q).unwrap_err();
+            assert!(matches!(e, Error::Validation(_)), "{q}");
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use super::client::ClientParser;
+use ua_parser::{device, os, user_agent};
+
+// The bundled `regexes.yaml` from the `ua` parser community,
+// and the bundled `bots.yaml`, that are converted by `build.rs`.
+//
+// To get the latest `regexes.yaml`:
+//   make update-regexes
+//
+include!(concat!(env!("OUT_DIR"), "/bundled.rs"));
+
+/// Returns the rules of the bundled `regexes.yaml`.
+pub(crate) fn regexes() -> ua_parser::Regexes<'static> {
+    let user_agent_parsers = USER_AGENT_PARSERS
+        .iter()
+        .map(|(regex, [family, v1, v2, v3, v4])| user_agent::Parser {
+            regex: (*regex).into(),
+            family_replacement: family.map(Into::into),
+            v1_replacement: v1.map(Into::into),
+            v2_replacement: v2.map(Into::into),
+            v3_replacement: v3.map(Into::into),
+            v4_replacement: v4.map(Into::into),
+        })
+        .collect();
+    let os_parsers = OS_PARSERS
+        .iter()
+        .map(|(regex, [name, v1, v2, v3, v4])| os::Parser {
+            regex: (*regex).into(),
+            os_replacement: name.map(Into::into),
+            os_v1_replacement: v1.map(Into::into),
+            os_v2_replacement: v2.map(Into::into),
+            os_v3_replacement: v3.map(Into::into),
+            os_v4_replacement: v4.map(Into::into),
+        })
+        .collect();
+    let device_parsers = DEVICE_PARSERS
+        .iter()
+        .map(|(regex, [flag, device, brand, model])| device::Parser {
+            regex: (*regex).into(),
+            regex_flag: (*flag == Some("i")).then_some(device::Flag::IgnoreCase),
+            device_replacement: device.map(Into::into),
+            brand_replacement: brand.map(Into::into),
+            model_replacement: model.map(Into::into),
+        })
+        .collect();
+    ua_parser::Regexes {
+        user_agent_parsers,
+        os_parsers,
+        device_parsers,
+    }
+}
+
+/// Returns the rules of the bundled `bots.yaml`.
+pub(crate) fn client_parsers() -> Vec<ClientParser> {
+    CLIENT_PARSERS
+        .iter()
+        .map(|(regex, [kind, name, vendor])| ClientParser {
+            regex: regex.to_string(),
+            // checked by `build.rs`
+            kind: kind.unwrap_or_default().parse().unwrap_or_default(),
+            name: name.unwrap_or_default().to_string(),
+            vendor: vendor.unwrap_or_default().to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_regexes() {
+        let regexes = regexes();
+        assert!(regexes.user_agent_parsers.len() > 100);
+        assert!(regexes.os_parsers.len() > 10);
+        assert!(regexes
+            .device_parsers
+            .iter()
+            .any(|a| a.regex_flag.is_some()));
+    }
+    #[test]
+    fn test_client_parsers() {
+        let parsers = client_parsers();
+        assert!(!parsers.is_empty());
+        assert!(parsers.iter().all(|a| a.kind.is_bot()));
+        super::super::client::ClientRules::new(parsers).unwrap();
+    }
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn test_same_as_yaml() {
+        let yaml: ua_parser::Regexes =
+            serde_yaml::from_str(include_str!("../../resources/regexes.yaml")).unwrap();
+        let regexes = regexes();
+        assert_eq!(
+            yaml.user_agent_parsers.len(),
+            regexes.user_agent_parsers.len()
+        );
+        assert_eq!(yaml.os_parsers.len(), regexes.os_parsers.len());
+        assert_eq!(yaml.device_parsers.len(), regexes.device_parsers.len());
+        for (a, b) in yaml
+            .device_parsers
+            .iter()
+            .zip(regexes.device_parsers.iter())
+        {
+            assert_eq!(a.regex, b.regex);
+            assert_eq!(a.model_replacement, b.model_replacement);
+            assert!(a.regex_flag == b.regex_flag);
+        }
+    }
+}
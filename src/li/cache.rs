@@ -0,0 +1,183 @@
+use super::Browser;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+/// The statistics of the parse cache.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CacheStats {
+    /// the number of the lookups that are found
+    pub hits: u64,
+    /// the number of the lookups that are parsed
+    pub misses: u64,
+    /// the number of the entries
+    pub len: usize,
+    /// the maximum number of the entries
+    pub capacity: usize,
+}
+
+impl CacheStats {
+    /// Returns the ratio of the hits, from 0.0 to 1.0.
+    pub fn hit_ratio(&self) -> f64 {
+        let total = self.hits + self.misses;
+        if total == 0 {
+            0.0
+        } else {
+            self.hits as f64 / total as f64
+        }
+    }
+}
+
+/// The bounded cache of the parsed results, keyed on the exact `user agent`.
+///
+/// This is a two generations cache, that approximates `LRU`:
+/// a found entry moves to the young generation, and when the young is full,
+/// the old is dropped and the young becomes the old.
+/// The entries of the old are dropped as well, to keep within the capacity.
+pub(crate) struct ParseCache {
+    capacity: usize,
+    generation_size: usize,
+    inner: Mutex<Generations>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+#[derive(Default)]
+struct Generations {
+    young: HashMap<String, Browser>,
+    old: HashMap<String, Browser>,
+}
+
+impl Generations {
+    fn insert(&mut self, capacity: usize, generation_size: usize, ua: String, browser: Browser) {
+        if self.young.len() >= generation_size {
+            self.old = std::mem::take(&mut self.young);
+        }
+        while self.young.len() + self.old.len() >= capacity {
+            let Some(key) = self.old.keys().next().cloned() else {
+                break;
+            };
+            self.old.remove(&key);
+        }
+        self.young.insert(ua, browser);
+    }
+}
+
+impl ParseCache {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            generation_size: capacity.div_ceil(2).max(1),
+            inner: Mutex::new(Generations::default()),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+    /// Returns the cached result, or parses with `f` and caches it.
+    pub(crate) fn get_or_insert_with<F>(&self, ua: &str, f: F) -> Browser
+    where
+        F: FnOnce() -> Browser,
+    {
+        {
+            let mut inner = self.lock();
+            if let Some(browser) = inner.young.get(ua) {
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                return browser.clone();
+            }
+            if let Some(browser) = inner.old.remove(ua) {
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                inner.insert(
+                    self.capacity,
+                    self.generation_size,
+                    ua.to_string(),
+                    browser.clone(),
+                );
+                return browser;
+            }
+        }
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        // parsing is done without the lock
+        let browser = f();
+        self.lock().insert(
+            self.capacity,
+            self.generation_size,
+            ua.to_string(),
+            browser.clone(),
+        );
+        browser
+    }
+    pub(crate) fn stats(&self) -> CacheStats {
+        let inner = self.lock();
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            len: inner.young.len() + inner.old.len(),
+            capacity: self.capacity,
+        }
+    }
+    pub(crate) fn clear(&self) {
+        *self.lock() = Generations::default();
+        self.hits.store(0, Ordering::Relaxed);
+        self.misses.store(0, Ordering::Relaxed);
+    }
+    fn lock(&self) -> std::sync::MutexGuard<'_, Generations> {
+        // the cache is still consistent after a panic in the other thread
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    fn browser(name: &str) -> Browser {
+        Browser {
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+    #[test]
+    fn test_hit_miss() {
+        let cache = ParseCache::new(4);
+        let b = cache.get_or_insert_with("a", || browser("A"));
+        assert_eq!(b.name, "A");
+        let b = cache.get_or_insert_with("a", || unreachable!());
+        assert_eq!(b.name, "A");
+        let stats = cache.stats();
+        assert_eq!((stats.hits, stats.misses, stats.len), (1, 1, 1));
+        assert_eq!(stats.hit_ratio(), 0.5);
+        cache.clear();
+        assert_eq!(
+            cache.stats(),
+            CacheStats {
+                capacity: 4,
+                ..Default::default()
+            }
+        );
+    }
+    #[test]
+    fn test_bounded() {
+        let cache = ParseCache::new(4);
+        for i in 0..100 {
+            cache.get_or_insert_with(&i.to_string(), || browser("X"));
+            assert!(cache.stats().len <= 4);
+        }
+        // the capacity is exact, even if it is odd
+        for capacity in [1, 3, 5] {
+            let cache = ParseCache::new(capacity);
+            assert_eq!(cache.stats().capacity, capacity);
+            for i in 0..20 {
+                cache.get_or_insert_with(&i.to_string(), || browser("X"));
+                assert!(cache.stats().len <= capacity);
+            }
+            assert_eq!(cache.stats().len, capacity);
+        }
+        // the recently used entry survives
+        let cache = ParseCache::new(4);
+        cache.get_or_insert_with("keep", || browser("K"));
+        for i in 0..10 {
+            cache.get_or_insert_with(&i.to_string(), || browser("X"));
+            let b = cache.get_or_insert_with("keep", || browser("miss"));
+            assert_eq!(b.name, "K");
+        }
+    }
+}
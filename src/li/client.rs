@@ -0,0 +1,218 @@
+use super::Error;
+#[cfg(feature = "extractor")]
+use super::{Browser, DeviceClass};
+use serde::{Deserialize, Serialize};
+
+/// The kind of the client.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum ClientKind {
+    /// can not be classified
+    #[default]
+    Unknown,
+    /// a browser that a human uses
+    Browser,
+    /// a crawler of a search engine or a SEO tool
+    SearchCrawler,
+    /// a crawler or a fetcher of an AI service
+    AiCrawler,
+    /// an uptime monitor or a health check
+    Monitoring,
+    /// a http library or a command line tool, as `curl`
+    HttpLibrary,
+    /// a headless browser, as `HeadlessChrome`
+    HeadlessAutomation,
+    /// a feed reader
+    FeedReader,
+    /// a crawler of the other kind, as a `Spider` that only `regexes.yaml` knows
+    Crawler,
+}
+
+impl ClientKind {
+    /// Returns true if the client is automated.
+    pub fn is_bot(&self) -> bool {
+        !matches!(self, ClientKind::Unknown | ClientKind::Browser)
+    }
+}
+
+impl std::fmt::Display for ClientKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let s = match self {
+            ClientKind::Unknown => "unknown",
+            ClientKind::Browser => "browser",
+            ClientKind::SearchCrawler => "search_crawler",
+            ClientKind::AiCrawler => "ai_crawler",
+            ClientKind::Monitoring => "monitoring",
+            ClientKind::HttpLibrary => "http_library",
+            ClientKind::HeadlessAutomation => "headless_automation",
+            ClientKind::FeedReader => "feed_reader",
+            ClientKind::Crawler => "crawler",
+        };
+        write!(f, "{s}")
+    }
+}
+
+impl std::str::FromStr for ClientKind {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let kind = match s {
+            "unknown" => ClientKind::Unknown,
+            "browser" => ClientKind::Browser,
+            "search_crawler" => ClientKind::SearchCrawler,
+            "ai_crawler" => ClientKind::AiCrawler,
+            "monitoring" => ClientKind::Monitoring,
+            "http_library" => ClientKind::HttpLibrary,
+            "headless_automation" => ClientKind::HeadlessAutomation,
+            "feed_reader" => ClientKind::FeedReader,
+            "crawler" => ClientKind::Crawler,
+            _ => return Err(Error::Validation(format!("unknown client kind: {s}"))),
+        };
+        Ok(kind)
+    }
+}
+
+/// The client information.
+/// This is the information obtained by parsing `user agent`
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct Client {
+    /// a client kind
+    pub kind: ClientKind,
+    /// a bot name, as `Googlebot`. It is empty for a browser.
+    pub name: String,
+    /// a bot vendor, as `Google`. It may be empty.
+    pub vendor: String,
+}
+
+/// A rule of `bots.yaml`.
+#[cfg(feature = "extractor")]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "yaml", derive(Deserialize))]
+pub(crate) struct ClientParser {
+    pub(crate) regex: String,
+    pub(crate) kind: ClientKind,
+    pub(crate) name: String,
+    #[cfg_attr(feature = "yaml", serde(default))]
+    pub(crate) vendor: String,
+}
+
+/// The compiled rules of `bots.yaml`.
+#[cfg(feature = "extractor")]
+pub(crate) struct ClientRules {
+    set: regex::RegexSet,
+    parsers: Vec<ClientParser>,
+}
+
+#[cfg(feature = "extractor")]
+impl ClientRules {
+    pub(crate) fn new(parsers: Vec<ClientParser>) -> Result<Self, regex::Error> {
+        let set = regex::RegexSet::new(parsers.iter().map(|a| &a.regex))?;
+        Ok(Self { set, parsers })
+    }
+    /// Classifies the client, and marks the device of a bot.
+    pub(crate) fn classify(&self, ua: &str, browser: &mut Browser) {
+        browser.client = match self.set.matches(ua).iter().next() {
+            Some(idx) => {
+                let p = &self.parsers[idx];
+                Client {
+                    kind: p.kind,
+                    name: p.name.clone(),
+                    vendor: p.vendor.clone(),
+                }
+            }
+            // a bot that only `regexes.yaml` knows
+            None if browser.device.family == "Spider" => Client {
+                kind: ClientKind::Crawler,
+                name: browser.name.clone(),
+                vendor: String::new(),
+            },
+            None if !browser.name.is_empty() && browser.name != "Other" => Client {
+                kind: ClientKind::Browser,
+                ..Default::default()
+            },
+            None => Client::default(),
+        };
+        // a headless browser runs on a real device
+        if browser.client.kind.is_bot() && browser.client.kind != ClientKind::HeadlessAutomation {
+            browser.device_class = DeviceClass::Bot;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    fn cli(ua: &str) -> Client {
+        super::super::try_init().unwrap().parse(ua).client
+    }
+    fn kind(ua: &str) -> ClientKind {
+        cli(ua).kind
+    }
+    #[cfg(feature = "extractor")]
+    #[test]
+    fn test_crawler() {
+        let a = cli("Mozilla/5.0 (compatible; Googlebot/2.1; +http://www.google.com/bot.html)");
+        assert_eq!(a.kind, ClientKind::SearchCrawler);
+        assert_eq!(a.name, "Googlebot");
+        assert_eq!(a.vendor, "Google");
+        let a = cli("Mozilla/5.0 AppleWebKit/537.36 (KHTML, like Gecko; compatible; GPTBot/1.2; +https://openai.com/gptbot)");
+        assert_eq!(a.kind, ClientKind::AiCrawler);
+        assert_eq!(a.vendor, "OpenAI");
+        let a = cli("Mozilla/5.0 AppleWebKit/537.36 (KHTML, like Gecko; compatible; ClaudeBot/1.0; +claudebot@anthropic.com)");
+        assert_eq!(a.kind, ClientKind::AiCrawler);
+        assert_eq!(a.name, "ClaudeBot");
+        assert_eq!(
+            kind("Mozilla/5.0 (compatible; bingbot/2.0; +http://www.bing.com/bingbot.htm)"),
+            ClientKind::SearchCrawler
+        );
+        // a spider that only `regexes.yaml` knows
+        let browser = super::super::try_init()
+            .unwrap()
+            .parse("Mozilla/5.0 (compatible; heritrix/3.4.0 +https://example.org/crawler)");
+        assert_eq!(browser.client.kind, ClientKind::Crawler);
+        assert!(browser.client.kind.is_bot());
+        assert_eq!(browser.device_class, DeviceClass::Bot);
+    }
+    #[cfg(feature = "extractor")]
+    #[test]
+    fn test_tools() {
+        assert_eq!(kind("curl/8.5.0"), ClientKind::HttpLibrary);
+        assert_eq!(kind("python-requests/2.32.3"), ClientKind::HttpLibrary);
+        assert_eq!(kind("Go-http-client/1.1"), ClientKind::HttpLibrary);
+        assert_eq!(
+            kind("Mozilla/5.0 (compatible; UptimeRobot/2.0; http://www.uptimerobot.com/)"),
+            ClientKind::Monitoring
+        );
+        assert_eq!(kind("kube-probe/1.30"), ClientKind::Monitoring);
+        let browser = super::super::try_init()
+            .unwrap()
+            .parse("Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) HeadlessChrome/131.0.0.0 Safari/537.36");
+        assert_eq!(browser.client.kind, ClientKind::HeadlessAutomation);
+        assert_eq!(browser.device_class, DeviceClass::Desktop);
+        let browser = super::super::try_init().unwrap().parse("curl/8.5.0");
+        assert_eq!(browser.device_class, DeviceClass::Bot);
+    }
+    #[test]
+    fn test_browser() {
+        let a =
+            cli("Mozilla/5.0 (X11; Ubuntu; Linux x86_64; rv:145.0) Gecko/20100101 Firefox/145.0");
+        assert_eq!(a.kind, ClientKind::Browser);
+        assert_eq!(a.name, "");
+        assert!(!a.kind.is_bot());
+        assert_eq!(kind(""), ClientKind::Unknown);
+    }
+    #[test]
+    fn test_serde() {
+        let s = serde_json::to_string(&ClientKind::AiCrawler).unwrap();
+        assert_eq!(s, r#""ai_crawler""#);
+        assert_eq!(ClientKind::AiCrawler.to_string(), "ai_crawler");
+        assert_eq!(
+            "ai_crawler".parse::<ClientKind>().unwrap(),
+            ClientKind::AiCrawler
+        );
+        assert_eq!(
+            "crawler".parse::<ClientKind>().unwrap(),
+            ClientKind::Crawler
+        );
+        assert!("robot".parse::<ClientKind>().is_err());
+    }
+}
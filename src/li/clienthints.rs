@@ -0,0 +1,530 @@
+use super::{Architecture, Browser, DeviceClass, Error, Os, Result, Runtime, Version};
+use serde::{Deserialize, Serialize};
+
+/// A brand and its version, as `"Google Chrome";v="120"` in `Sec-CH-UA`.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct Brand {
+    /// a brand name
+    pub brand: String,
+    /// a brand version
+    pub version: Version,
+}
+
+// the characters of the `GREASE` brands, as `(` of `Not A(Brand`
+const GREASE_CHARS: &[char] = &[' ', '(', ':', '-', '.', '/', ')', ';', '=', '?', '_'];
+
+impl Brand {
+    /// Returns true if self is a `GREASE` brand, as `Not A(Brand` or ` Not A;Brand`.
+    ///
+    /// It is `Not`, `A` and `Brand` with the `GREASE` characters.
+    pub fn is_grease(&self) -> bool {
+        let mut letters = self.brand.chars().filter(|c| !GREASE_CHARS.contains(c));
+        letters.by_ref().take(9).eq("NotABrand".chars()) && letters.next().is_none()
+    }
+}
+
+/// The User-Agent Client Hints.
+///
+/// This is the information obtained from the `Sec-CH-UA*` request headers.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct ClientHints {
+    /// `Sec-CH-UA`
+    pub brands: Vec<Brand>,
+    /// `Sec-CH-UA-Full-Version-List`
+    pub full_version_list: Vec<Brand>,
+    /// `Sec-CH-UA-Platform`
+    pub platform: String,
+    /// `Sec-CH-UA-Platform-Version`
+    pub platform_version: Version,
+    /// `Sec-CH-UA-Model`
+    pub model: String,
+    /// `Sec-CH-UA-Arch`
+    pub arch: String,
+    /// `Sec-CH-UA-Bitness`
+    pub bitness: String,
+    /// `Sec-CH-UA-Mobile`
+    pub mobile: Option<bool>,
+}
+
+impl ClientHints {
+    /// Creates an object from the request headers.
+    ///
+    /// The header names are case-insensitive,
+    /// and the headers that are not client hints are ignored.
+    pub fn from_headers<I, K, V>(headers: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let mut ch = Self::default();
+        for (name, value) in headers {
+            ch.set_header(name.as_ref(), value.as_ref())?;
+        }
+        Ok(ch)
+    }
+    /// Sets the value of a header.
+    ///
+    /// Returns false if the header is not a client hint.
+    pub fn set_header(&mut self, name: &str, value: &str) -> Result<bool> {
+        let invalid = |e: &str| Error::Validation(format!("{name}: {e}"));
+        match name.to_ascii_lowercase().as_str() {
+            "sec-ch-ua" => self.brands = parse_brand_list(value).map_err(invalid)?,
+            "sec-ch-ua-full-version-list" => {
+                self.full_version_list = parse_brand_list(value).map_err(invalid)?
+            }
+            "sec-ch-ua-platform" => self.platform = parse_string(value).map_err(invalid)?,
+            "sec-ch-ua-platform-version" => {
+                self.platform_version = Version::parse(&parse_string(value).map_err(invalid)?)
+            }
+            "sec-ch-ua-model" => self.model = parse_string(value).map_err(invalid)?,
+            "sec-ch-ua-arch" => self.arch = parse_string(value).map_err(invalid)?,
+            "sec-ch-ua-bitness" => self.bitness = parse_string(value).map_err(invalid)?,
+            "sec-ch-ua-mobile" => self.mobile = Some(parse_boolean(value).map_err(invalid)?),
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+    /// Returns true if no hint is set.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+    /// Returns the brand that names the browser.
+    ///
+    /// `GREASE` brands are skipped, and `Chromium` is used
+    /// only if there is no other brand.
+    /// The version is taken from `full_version_list` if it is set.
+    pub fn significant_brand(&self) -> Option<&Brand> {
+        let list = if self.full_version_list.is_empty() {
+            &self.brands
+        } else {
+            &self.full_version_list
+        };
+        let mut it = list.iter().filter(|a| !a.is_grease());
+        it.clone()
+            .find(|a| a.brand != "Chromium")
+            .or_else(|| it.next())
+    }
+    /// Refines `browser` generated from `user agent`.
+    ///
+    /// The reduced `user agent` of `Chromium` freezes the minor versions,
+    /// the operating system version and the device model.
+    /// The values of the hints take priority over them.
+    pub fn refine(&self, browser: &mut Browser) {
+        let mobile = self.mobile.unwrap_or(false);
+        if let Some(brand) = self.significant_brand() {
+            let name = browser_family(&brand.brand, mobile);
+            // the version of `Sec-CH-UA` has only the major
+            if name != browser.name || !self.full_version_list.is_empty() {
+                browser.version = brand.version.clone();
+            }
+            browser.name = name;
+        }
+        if browser.engine.name == "Blink" {
+            // the `Chromium` brand is the `Blink` version
+            let chromium = self
+                .full_version_list
+                .iter()
+                .find(|a| a.brand == "Chromium");
+            if let Some(brand) = chromium {
+                browser.engine.version = brand.version.clone();
+            }
+        }
+        if !self.platform.is_empty() {
+            let name = os_family(&self.platform);
+            let os = browser.os.get_or_insert_with(Os::default);
+            if os.name != name || !self.platform_version.is_empty() {
+                os.version = os_version(&self.platform, &self.platform_version);
+                os.exact = !os.version.is_empty();
+            }
+            os.name = name;
+        }
+        if self.mobile == Some(true) {
+            browser.device_class = DeviceClass::Mobile;
+        }
+        // `WebView2` has the brand, as the additional one of `Microsoft Edge`
+        if self
+            .brands
+            .iter()
+            .any(|a| a.brand == "Microsoft Edge WebView2")
+        {
+            browser.runtime = Runtime::WebView2;
+        } else if self.brands.iter().any(|a| a.brand == "Android WebView") {
+            browser.runtime = Runtime::AndroidWebView;
+        }
+        // the unknown one, as `"riscv"`, keeps the one of `user agent`
+        let architecture = Architecture::from_hints(&self.arch, &self.bitness);
+        if architecture.is_known() {
+            browser.architecture = architecture;
+        }
+        if !self.model.is_empty() {
+            let dev = &mut browser.device;
+            if dev.brand.starts_with("Generic") {
+                dev.brand = String::new();
+            }
+            dev.family = self.model.clone();
+            dev.model = self.model.clone();
+        }
+    }
+}
+
+// the brand name to the family name of `regexes.yaml`
+fn browser_family(brand: &str, mobile: bool) -> String {
+    let (name, mobile_name) = match brand {
+        "Google Chrome" => ("Chrome", "Chrome Mobile"),
+        "Microsoft Edge" | "Microsoft Edge WebView2" => ("Edge", "Edge Mobile"),
+        "Opera" => ("Opera", "Opera Mobile"),
+        "Android WebView" => ("Chrome Mobile WebView", "Chrome Mobile WebView"),
+        "YaBrowser" => ("Yandex Browser", "Yandex Browser"),
+        _ => (brand, brand),
+    };
+    if mobile { mobile_name } else { name }.to_string()
+}
+
+// the platform name to the family name of `regexes.yaml`
+fn os_family(platform: &str) -> String {
+    match platform {
+        "macOS" => "Mac OS X",
+        "Chrome OS" | "Chromium OS" => "Chrome OS",
+        _ => platform,
+    }
+    .to_string()
+}
+
+// the platform version of `Windows` is not the `Windows` version
+fn os_version(platform: &str, version: &Version) -> Version {
+    if platform != "Windows" {
+        return version.clone();
+    }
+    let s = match (version.major, version.minor) {
+        (Some(13..), _) => "11",
+        (Some(1..), _) => "10",
+        (Some(0), Some(3)) => "8.1",
+        (Some(0), Some(2)) => "8",
+        (Some(0), Some(1)) => "7",
+        _ => "",
+    };
+    Version::parse(s)
+}
+
+//
+// Structured Field Values for HTTP (RFC 8941), only what the client hints use.
+//
+
+fn parse_string(value: &str) -> std::result::Result<String, &'static str> {
+    let mut p = SfParser::new(value);
+    p.skip_sp();
+    let s = p.string()?;
+    p.skip_sp();
+    p.end()?;
+    Ok(s)
+}
+
+fn parse_boolean(value: &str) -> std::result::Result<bool, &'static str> {
+    match value.trim() {
+        "?1" => Ok(true),
+        "?0" => Ok(false),
+        _ => Err("expected a boolean"),
+    }
+}
+
+fn parse_brand_list(value: &str) -> std::result::Result<Vec<Brand>, &'static str> {
+    let mut p = SfParser::new(value);
+    let mut list = Vec::new();
+    p.skip_sp();
+    if p.is_end() {
+        return Ok(list);
+    }
+    loop {
+        let brand = p.string()?;
+        let mut version = String::new();
+        while p.eat(';') {
+            p.skip_sp();
+            let key = p.key()?;
+            let val = if p.eat('=') {
+                p.bare_item()?
+            } else {
+                String::new()
+            };
+            if key == "v" {
+                version = val;
+            }
+        }
+        list.push(Brand {
+            brand,
+            version: Version::parse(&version),
+        });
+        p.skip_sp();
+        if p.is_end() {
+            break;
+        }
+        if !p.eat(',') {
+            return Err("expected a comma");
+        }
+        p.skip_sp();
+    }
+    Ok(list)
+}
+
+struct SfParser<'a> {
+    s: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> SfParser<'a> {
+    fn new(s: &'a str) -> Self {
+        Self {
+            s: s.as_bytes(),
+            pos: 0,
+        }
+    }
+    fn peek(&self) -> Option<u8> {
+        self.s.get(self.pos).copied()
+    }
+    fn is_end(&self) -> bool {
+        self.pos >= self.s.len()
+    }
+    fn end(&self) -> std::result::Result<(), &'static str> {
+        if self.is_end() {
+            Ok(())
+        } else {
+            Err("unexpected trailing characters")
+        }
+    }
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c as u8) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+    fn skip_sp(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t')) {
+            self.pos += 1;
+        }
+    }
+    fn string(&mut self) -> std::result::Result<String, &'static str> {
+        if !self.eat('"') {
+            return Err("expected a string");
+        }
+        let mut out = Vec::new();
+        loop {
+            match self.peek() {
+                None => return Err("unterminated string"),
+                Some(b'"') => {
+                    self.pos += 1;
+                    break;
+                }
+                Some(b'\\') => {
+                    self.pos += 1;
+                    match self.peek() {
+                        Some(c @ (b'"' | b'\\')) => out.push(c),
+                        _ => return Err("invalid escape"),
+                    }
+                    self.pos += 1;
+                }
+                Some(c @ 0x20..=0x7e) => {
+                    out.push(c);
+                    self.pos += 1;
+                }
+                Some(_) => return Err("invalid character in string"),
+            }
+        }
+        // only ascii is pushed
+        Ok(String::from_utf8(out).unwrap_or_default())
+    }
+    fn key(&mut self) -> std::result::Result<String, &'static str> {
+        let start = self.pos;
+        while matches!(
+            self.peek(),
+            Some(b'a'..=b'z' | b'0'..=b'9' | b'_' | b'-' | b'.' | b'*')
+        ) {
+            self.pos += 1;
+        }
+        if start == self.pos {
+            return Err("expected a key");
+        }
+        Ok(String::from_utf8_lossy(&self.s[start..self.pos]).into_owned())
+    }
+    // a string, a token, a number or a boolean
+    fn bare_item(&mut self) -> std::result::Result<String, &'static str> {
+        if self.peek() == Some(b'"') {
+            return self.string();
+        }
+        let start = self.pos;
+        while matches!(self.peek(), Some(c) if c.is_ascii_graphic() && !b",;=\"".contains(&c)) {
+            self.pos += 1;
+        }
+        if start == self.pos {
+            return Err("expected an item");
+        }
+        Ok(String::from_utf8_lossy(&self.s[start..self.pos]).into_owned())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    fn headers() -> Vec<(&'static str, &'static str)> {
+        vec![
+            (
+                "Sec-CH-UA",
+                r#""Chromium";v="142", "Google Chrome";v="142", "Not_A Brand";v="99""#,
+            ),
+            (
+                "Sec-CH-UA-Full-Version-List",
+                r#""Chromium";v="142.0.7444.102", "Google Chrome";v="142.0.7444.102", "Not_A Brand";v="99.0.0.0""#,
+            ),
+            ("Sec-CH-UA-Platform", r#""Android""#),
+            ("Sec-CH-UA-Platform-Version", r#""14.0.0""#),
+            ("Sec-CH-UA-Model", r#""Pixel 7""#),
+            ("Sec-CH-UA-Arch", r#""""#),
+            ("Sec-CH-UA-Bitness", r#""64""#),
+            ("Sec-CH-UA-Mobile", "?1"),
+            ("Accept", "*/*"),
+        ]
+    }
+    #[test]
+    fn test_from_headers() {
+        let ch = ClientHints::from_headers(headers()).unwrap();
+        assert_eq!(ch.brands.len(), 3);
+        assert_eq!(ch.brands[1].brand, "Google Chrome");
+        assert_eq!(ch.brands[1].version.to_string(), "142");
+        assert!(ch.brands[2].is_grease());
+        assert_eq!(
+            ch.full_version_list[0].version.to_string(),
+            "142.0.7444.102"
+        );
+        assert_eq!(ch.platform, "Android");
+        assert_eq!(ch.platform_version.to_string(), "14.0.0");
+        assert_eq!(ch.model, "Pixel 7");
+        assert_eq!(ch.arch, "");
+        assert_eq!(ch.bitness, "64");
+        assert_eq!(ch.mobile, Some(true));
+        let brand = ch.significant_brand().unwrap();
+        assert_eq!(brand.brand, "Google Chrome");
+        assert!(!ch.is_empty());
+        assert!(ClientHints::default().is_empty());
+    }
+    #[test]
+    fn test_grease() {
+        // the leading space of `Chrome 89` and later
+        let ch = ClientHints::from_headers([(
+            "sec-ch-ua",
+            r#"" Not A;Brand";v="99", "Chromium";v="90", "Google Chrome";v="90""#,
+        )])
+        .unwrap();
+        assert!(ch.brands[0].is_grease());
+        let brand = ch.significant_brand().unwrap();
+        assert_eq!(brand.brand, "Google Chrome");
+        assert_eq!(brand.version.to_string(), "90");
+        for s in [
+            " Not;A Brand",
+            "Not A(Brand",
+            "Not/A)Brand",
+            "Not)A;Brand",
+            "Not=A?Brand",
+        ] {
+            let brand = Brand {
+                brand: s.to_string(),
+                ..Default::default()
+            };
+            assert!(brand.is_grease(), "{s}");
+        }
+        for s in ["Notable Brand", "Not A Brand Browser", "Brand"] {
+            let brand = Brand {
+                brand: s.to_string(),
+                ..Default::default()
+            };
+            assert!(!brand.is_grease(), "{s}");
+        }
+    }
+    #[test]
+    fn test_invalid() {
+        let r = ClientHints::from_headers([("sec-ch-ua-mobile", "1")]);
+        assert!(matches!(r, Err(Error::Validation(_))));
+        let r = ClientHints::from_headers([("sec-ch-ua-platform", "Windows")]);
+        assert!(matches!(r, Err(Error::Validation(_))));
+        let r = ClientHints::from_headers([("sec-ch-ua", r#""Chromium";v="142" "Edge""#)]);
+        assert!(matches!(r, Err(Error::Validation(_))));
+        let r = ClientHints::from_headers([("sec-ch-ua-model", r#""Pixel"#)]);
+        assert!(matches!(r, Err(Error::Validation(_))));
+    }
+    #[test]
+    fn test_string_escape() {
+        assert_eq!(parse_string(r#" "a\"b\\c" "#).unwrap(), r#"a"b\c"#);
+        assert_eq!(parse_brand_list("").unwrap(), vec![]);
+    }
+    #[cfg(feature = "extractor")]
+    #[test]
+    fn test_refine_android() {
+        let ua = r#"Mozilla/5.0 (Linux; Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/142.0.0.0 Mobile Safari/537.36"#;
+        let mut browser = super::super::try_init().unwrap().parse(ua);
+        assert_eq!(browser.device.to_string(), "Generic_Android/K");
+        let ch = ClientHints::from_headers(headers()).unwrap();
+        ch.refine(&mut browser);
+        assert_eq!(browser.name, "Chrome Mobile");
+        assert_eq!(browser.version.to_string(), "142.0.7444.102");
+        let os = browser.os.as_ref().unwrap();
+        assert_eq!(os.name, "Android");
+        assert_eq!(os.version.to_string(), "14.0.0");
+        assert_eq!(browser.device.to_string(), "Pixel 7");
+    }
+    #[test]
+    fn test_refine_windows() {
+        let ua = r#"Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/142.0.0.0 Safari/537.36 Edg/142.0.0.0"#;
+        let mut browser = super::super::try_init().unwrap().parse(ua);
+        let ch = ClientHints::from_headers([
+            (
+                "sec-ch-ua",
+                r#""Chromium";v="142", "Microsoft Edge";v="142", "Not_A Brand";v="99""#,
+            ),
+            ("sec-ch-ua-mobile", "?0"),
+            ("sec-ch-ua-platform", r#""Windows""#),
+            ("sec-ch-ua-platform-version", r#""19.0.0""#),
+        ])
+        .unwrap();
+        ch.refine(&mut browser);
+        assert_eq!(browser.name, "Edge");
+        assert_eq!(browser.version.to_string(), "142.0.0.0");
+        let os = browser.os.as_ref().unwrap();
+        assert_eq!(os.name, "Windows");
+        assert_eq!(os.version.to_string(), "11");
+        assert!(os.exact);
+        // the frozen `x64` of `Windows` on `ARM`
+        assert_eq!(browser.architecture, Architecture::X86_64);
+        let ch = ClientHints::from_headers([
+            ("sec-ch-ua-arch", r#""arm""#),
+            ("sec-ch-ua-bitness", r#""64""#),
+        ])
+        .unwrap();
+        ch.refine(&mut browser);
+        assert_eq!(browser.architecture, Architecture::Arm64);
+        let ch = ClientHints::from_headers([
+            ("sec-ch-ua-arch", r#""riscv""#),
+            ("sec-ch-ua-bitness", r#""64""#),
+        ])
+        .unwrap();
+        ch.refine(&mut browser);
+        assert_eq!(browser.architecture, Architecture::Arm64);
+        assert_eq!(browser.runtime, Runtime::BrowserTab);
+        let ch = ClientHints::from_headers([(
+            "sec-ch-ua",
+            r#""Microsoft Edge";v="142", "Microsoft Edge WebView2";v="142", "Chromium";v="142""#,
+        )])
+        .unwrap();
+        ch.refine(&mut browser);
+        assert_eq!(browser.name, "Edge");
+        assert_eq!(browser.runtime, Runtime::WebView2);
+        //
+        assert_eq!(
+            os_version("Windows", &Version::parse("10.0.0")).to_string(),
+            "10"
+        );
+        assert_eq!(
+            os_version("Windows", &Version::parse("0.3.0")).to_string(),
+            "8.1"
+        );
+    }
+}
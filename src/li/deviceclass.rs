@@ -0,0 +1,285 @@
+use super::{Device, JsInfo};
+use serde::{Deserialize, Serialize};
+
+/// The form factor of the device.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum DeviceClass {
+    /// can not be classified
+    #[default]
+    Unknown,
+    /// a desktop or a laptop
+    Desktop,
+    /// a phone
+    Mobile,
+    /// a tablet
+    Tablet,
+    /// a smart TV or a streaming box
+    Tv,
+    /// a game console
+    Console,
+    /// a watch
+    Wearable,
+    /// a bot, as a crawler
+    Bot,
+}
+
+impl DeviceClass {
+    /// Returns true if self is `Mobile` or `Tablet`.
+    pub fn is_handheld(&self) -> bool {
+        matches!(self, DeviceClass::Mobile | DeviceClass::Tablet)
+    }
+    /// Refines the class with the information obtained with `javascript`.
+    ///
+    /// `os_name` is the name of the parsed operating system.
+    /// The shortest side of the screen separates an `Android` tablet
+    /// from a phone, and the touch points find an `iPad`
+    /// that pretends to be a `Mac`.
+    pub fn refine(self, os_name: &str, jsinfo: &JsInfo) -> Self {
+        let touch = jsinfo.max_touch_points.map(|a| a > 1);
+        let shortest = match (jsinfo.screen_width, jsinfo.screen_height) {
+            (Some(w), Some(h)) if w > 0 && h > 0 => Some(w.min(h)),
+            _ => None,
+        };
+        match self {
+            DeviceClass::Mobile | DeviceClass::Tablet if os_name == "Android" => match shortest {
+                Some(a) if a >= TABLET_SHORTEST_SIDE => DeviceClass::Tablet,
+                Some(_) => DeviceClass::Mobile,
+                None => self,
+            },
+            DeviceClass::Desktop if jsinfo.platform == "MacIntel" && touch == Some(true) => {
+                DeviceClass::Tablet
+            }
+            DeviceClass::Unknown => match (touch, shortest) {
+                (Some(true), Some(a)) if a >= TABLET_SHORTEST_SIDE => DeviceClass::Tablet,
+                (Some(true), Some(_)) => DeviceClass::Mobile,
+                (Some(false), Some(_)) => DeviceClass::Desktop,
+                _ => self,
+            },
+            _ => self,
+        }
+    }
+}
+
+impl std::fmt::Display for DeviceClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let s = match self {
+            DeviceClass::Unknown => "unknown",
+            DeviceClass::Desktop => "desktop",
+            DeviceClass::Mobile => "mobile",
+            DeviceClass::Tablet => "tablet",
+            DeviceClass::Tv => "tv",
+            DeviceClass::Console => "console",
+            DeviceClass::Wearable => "wearable",
+            DeviceClass::Bot => "bot",
+        };
+        write!(f, "{s}")
+    }
+}
+
+// in CSS pixels, as the `sw600dp` of `Android`
+const TABLET_SHORTEST_SIDE: i32 = 600;
+
+const CONSOLE_TOKENS: &[&str] = &["PlayStation", "Xbox", "Nintendo"];
+
+const TV_TOKENS: &[&str] = &[
+    "SmartTV",
+    "SMART-TV",
+    "Smart TV",
+    "SmartTv",
+    "GoogleTV",
+    "Android TV",
+    "AppleTV",
+    "Apple TV",
+    "HbbTV",
+    "CrKey",
+    "BRAVIA",
+    "Web0S",
+    "NetCast",
+    "Roku",
+    "AFTB",
+    "AFTM",
+    "AFTS",
+    "AFTT",
+    "; TV ",
+];
+
+// `SM-R` is the model of `Galaxy Watch`, as `Android 11; SM-R890`
+const WEARABLE_TOKENS: &[&str] = &["watchOS", "Watch OS", "Wear OS", "WearOS", "; SM-R"];
+
+// `Tablet` is of `Firefox`, as `Android 14; Tablet; rv:128.0`,
+// not of `Tablet PC` of `Windows`.
+const TABLET_TOKENS: &[&str] = &[
+    "iPad",
+    "; Tablet;",
+    "; Tablet)",
+    "Kindle",
+    "Silk/",
+    "PlayBook",
+];
+
+const MOBILE_TOKENS: &[&str] = &[
+    "Mobile",
+    "iPhone",
+    "iPod",
+    "Windows Phone",
+    "BlackBerry",
+    "BB10",
+    "Opera Mini",
+    "IEMobile",
+];
+
+const DESKTOP_OS: &[&str] = &[
+    "Windows",
+    "Mac OS X",
+    "Linux",
+    "Ubuntu",
+    "Debian",
+    "Fedora",
+    "Red Hat",
+    "SUSE",
+    "Arch Linux",
+    "Chrome OS",
+    "FreeBSD",
+    "OpenBSD",
+    "NetBSD",
+];
+
+const DESKTOP_TOKENS: &[&str] = &["Windows NT", "Macintosh", "X11", "CrOS"];
+
+/// Classifies the device with the `user agent` and the parsed result.
+pub(crate) fn classify(ua: &str, os_name: &str, device: &Device) -> DeviceClass {
+    let has = |tokens: &[&str]| tokens.iter().any(|a| ua.contains(a));
+    if device.family == "Spider" {
+        return DeviceClass::Bot;
+    }
+    if has(CONSOLE_TOKENS) {
+        return DeviceClass::Console;
+    }
+    if has(TV_TOKENS) {
+        return DeviceClass::Tv;
+    }
+    if has(WEARABLE_TOKENS) {
+        return DeviceClass::Wearable;
+    }
+    if has(TABLET_TOKENS) {
+        return DeviceClass::Tablet;
+    }
+    if os_name == "Android" || ua.contains("Android") {
+        // `Chrome` on an `Android` tablet does not send `Mobile`,
+        // an app without the browser token is mostly on a phone.
+        if !ua.contains("Mobile") && ua.contains("Safari/") {
+            return DeviceClass::Tablet;
+        }
+        return DeviceClass::Mobile;
+    }
+    if has(MOBILE_TOKENS) || os_name == "iOS" {
+        return DeviceClass::Mobile;
+    }
+    if DESKTOP_OS.contains(&os_name) || has(DESKTOP_TOKENS) {
+        return DeviceClass::Desktop;
+    }
+    DeviceClass::Unknown
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[cfg(feature = "extractor")]
+    fn cls(ua: &str) -> DeviceClass {
+        super::super::try_init().unwrap().parse(ua).device_class
+    }
+    #[cfg(feature = "extractor")]
+    #[test]
+    fn test_classify() {
+        assert_eq!(
+            cls("Mozilla/5.0 (Windows NT 10.0; Win64; x64; rv:145.0) Gecko/20100101 Firefox/145.0"),
+            DeviceClass::Desktop
+        );
+        assert_eq!(
+            cls("Mozilla/5.0 (Linux; Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/142.0.0.0 Mobile Safari/537.36"),
+            DeviceClass::Mobile
+        );
+        assert_eq!(
+            cls("Mozilla/5.0 (Linux; Android 14; SM-X710) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/142.0.0.0 Safari/537.36"),
+            DeviceClass::Tablet
+        );
+        assert_eq!(
+            cls("Mozilla/5.0 (iPad; CPU OS 17_5 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.5 Mobile/15E148 Safari/604.1"),
+            DeviceClass::Tablet
+        );
+        assert_eq!(
+            cls("Mozilla/5.0 (PlayStation; PlayStation 5/2.26) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/13.0 Safari/605.1.15"),
+            DeviceClass::Console
+        );
+        assert_eq!(
+            cls("Mozilla/5.0 (Linux; Android 9; AFTS Build/PS7633; wv) AppleWebKit/537.36 (KHTML, like Gecko) Version/4.0 Chrome/130.0.6723.102 Mobile Safari/537.36"),
+            DeviceClass::Tv
+        );
+        assert_eq!(
+            cls("Mozilla/5.0 (compatible; Googlebot/2.1; +http://www.google.com/bot.html)"),
+            DeviceClass::Bot
+        );
+        assert_eq!(
+            cls("Mozilla/5.0 (Android 14; Tablet; rv:128.0) Gecko/128.0 Firefox/128.0"),
+            DeviceClass::Tablet
+        );
+        assert_eq!(
+            cls("Mozilla/5.0 (Linux; Android 11; SM-R890 Build/RP1A.200720.012) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/114.0.5735.196 Mobile Safari/537.36"),
+            DeviceClass::Wearable
+        );
+        assert_eq!(cls(""), DeviceClass::Unknown);
+    }
+    #[test]
+    fn test_classify_tokens() {
+        let device = Device::default();
+        // `Tablet PC` is a desktop
+        let ua = "Mozilla/5.0 (Windows NT 6.1; Win64; x64; Trident/7.0; Tablet PC 2.0; rv:11.0) like Gecko";
+        assert_eq!(classify(ua, "Windows", &device), DeviceClass::Desktop);
+        // `SM-R` is not a token of another word
+        let ua = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/142.0.0.0 Safari/537.36 RSM-Reader/2.1";
+        assert_eq!(classify(ua, "Windows", &device), DeviceClass::Desktop);
+    }
+    #[test]
+    fn test_refine() {
+        let mut jsinfo = JsInfo {
+            screen_width: Some(800),
+            screen_height: Some(1280),
+            ..Default::default()
+        };
+        assert_eq!(
+            DeviceClass::Mobile.refine("Android", &jsinfo),
+            DeviceClass::Tablet
+        );
+        jsinfo.screen_width = Some(412);
+        jsinfo.screen_height = Some(915);
+        assert_eq!(
+            DeviceClass::Tablet.refine("Android", &jsinfo),
+            DeviceClass::Mobile
+        );
+        // iPad on the desktop mode
+        jsinfo.platform = "MacIntel".to_string();
+        jsinfo.max_touch_points = Some(5);
+        assert_eq!(
+            DeviceClass::Desktop.refine("Mac OS X", &jsinfo),
+            DeviceClass::Tablet
+        );
+        jsinfo.max_touch_points = Some(0);
+        assert_eq!(
+            DeviceClass::Desktop.refine("Mac OS X", &jsinfo),
+            DeviceClass::Desktop
+        );
+        assert_eq!(
+            DeviceClass::Unknown.refine("", &jsinfo),
+            DeviceClass::Desktop
+        );
+    }
+    #[test]
+    fn test_serde() {
+        let s = serde_json::to_string(&DeviceClass::Tv).unwrap();
+        assert_eq!(s, r#""tv""#);
+        assert_eq!(DeviceClass::Tv.to_string(), "tv");
+        let a: DeviceClass = serde_json::from_str(r#""tablet""#).unwrap();
+        assert_eq!(a, DeviceClass::Tablet);
+    }
+}
@@ -0,0 +1,121 @@
+use super::Device;
+#[cfg(feature = "yaml")]
+use serde::Deserialize;
+
+// The device models and their marketing names,
+// that are converted from `resources/device_models.yaml` by `build.rs`.
+include!(concat!(env!("OUT_DIR"), "/device_models.rs"));
+
+/// A mapping of `device_models.yaml`.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "yaml", derive(Deserialize))]
+pub(crate) struct DeviceModel {
+    pub(crate) model: String,
+    pub(crate) manufacturer: String,
+    pub(crate) name: String,
+}
+
+/// The contents of `device_models.yaml`.
+#[cfg(feature = "yaml")]
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub(crate) struct DeviceModelFile {
+    pub(crate) device_models: Vec<DeviceModel>,
+}
+
+/// The lookup of the marketing names, the added mappings and the bundled ones.
+pub(crate) struct DeviceModels {
+    // the longest model first, and the added one first in the same length.
+    // the model is in the upper case.
+    models: Vec<DeviceModel>,
+}
+
+impl DeviceModels {
+    pub(crate) fn new(added: Vec<DeviceModel>) -> Self {
+        let bundled = DEVICE_MODELS
+            .iter()
+            .map(|(model, manufacturer, name)| DeviceModel {
+                model: model.to_string(),
+                manufacturer: manufacturer.to_string(),
+                name: name.to_string(),
+            });
+        let mut models: Vec<DeviceModel> = added
+            .into_iter()
+            .chain(bundled)
+            .filter(|a| !a.model.is_empty())
+            .map(|a| DeviceModel {
+                model: a.model.to_ascii_uppercase(),
+                ..a
+            })
+            .collect();
+        // stable, so that the added one stays ahead
+        models.sort_by_key(|a| std::cmp::Reverse(a.model.len()));
+        Self { models }
+    }
+    /// Sets the manufacturer and the marketing name of the device,
+    /// or clears them if the model is not found.
+    pub(crate) fn name(&self, device: &mut Device) {
+        let model = device.model.to_ascii_uppercase();
+        let found = (!model.is_empty())
+            .then(|| self.models.iter().find(|a| model.starts_with(&a.model)))
+            .flatten();
+        match found {
+            Some(a) => {
+                device.manufacturer = a.manufacturer.clone();
+                device.marketing_name = a.name.clone();
+            }
+            None => {
+                device.manufacturer.clear();
+                device.marketing_name.clear();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    fn name(models: &DeviceModels, model: &str) -> String {
+        let mut device = Device {
+            model: model.to_string(),
+            ..Default::default()
+        };
+        models.name(&mut device);
+        format!("{} {}", device.manufacturer, device.marketing_name)
+    }
+    #[test]
+    fn test_bundled() {
+        let models = DeviceModels::new(Vec::new());
+        assert_eq!(name(&models, "SM-S918B"), "Samsung Galaxy S23 Ultra");
+        assert_eq!(name(&models, "SM-S928B/DS"), "Samsung Galaxy S24 Ultra");
+        assert_eq!(name(&models, "sm-f9560"), "Samsung Galaxy Z Fold6");
+        assert_eq!(name(&models, "Pixel 7"), "Google Pixel 7");
+        assert_eq!(name(&models, "Pixel 7a"), "Google Pixel 7a");
+        assert_eq!(name(&models, "Pixel 9 Pro XL"), "Google Pixel 9 Pro XL");
+        assert_eq!(name(&models, "VOG-L29"), "Huawei P30 Pro");
+        assert_eq!(name(&models, "K"), " ");
+        assert_eq!(name(&models, ""), " ");
+    }
+    #[test]
+    fn test_added() {
+        let added = vec![
+            DeviceModel {
+                model: "SM-S918".to_string(),
+                manufacturer: "Samsung".to_string(),
+                name: "Galaxy S23 Ultra (added)".to_string(),
+            },
+            DeviceModel {
+                model: "S5-SH".to_string(),
+                manufacturer: "Sharp".to_string(),
+                name: "Simple Smartphone 5".to_string(),
+            },
+        ];
+        let models = DeviceModels::new(added);
+        assert_eq!(
+            name(&models, "SM-S918U"),
+            "Samsung Galaxy S23 Ultra (added)"
+        );
+        assert_eq!(name(&models, "S5-SH"), "Sharp Simple Smartphone 5");
+        assert_eq!(name(&models, "SM-S911B"), "Samsung Galaxy S23");
+    }
+}
@@ -0,0 +1,201 @@
+use super::Version;
+use serde::{Deserialize, Serialize};
+
+/// The rendering engine information.
+/// This is the information obtained by parsing `user agent`
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct Engine {
+    /// a engine name, as `Blink`, `Gecko` or `WebKit`
+    pub name: String,
+    /// a engine version
+    pub version: Version,
+}
+
+impl Engine {
+    fn new(name: &str, version: Version) -> Self {
+        Self {
+            name: name.to_string(),
+            version,
+        }
+    }
+}
+
+// the first version of `Blink`
+const BLINK_SINCE: u32 = 28;
+
+/// Detects the rendering engine from the tokens of `user agent`.
+///
+/// The product and the engine often diverge:
+/// every browser on `iOS` is `WebKit`, and `Edge`, `Opera`, `Brave`
+/// and `Samsung Internet` are `Blink` with the `Chrome` version.
+pub(crate) fn detect_engine(ua: &str, os_name: &str) -> Engine {
+    let webkit = token_version(ua, "AppleWebKit/");
+    let is_ios = matches!(os_name, "iOS" | "iPadOS")
+        || (ua.contains("like Mac OS X")
+            && ["iPhone", "iPad", "iPod"].iter().any(|a| ua.contains(a)));
+    if is_ios {
+        if let Some(v) = webkit {
+            return Engine::new("WebKit", v);
+        }
+    }
+    if let Some(v) = token_version(ua, "Trident/") {
+        return Engine::new("Trident", v);
+    }
+    if let Some(v) = token_version(ua, "Edge/") {
+        return Engine::new("EdgeHTML", v);
+    }
+    if let Some(v) = token_version(ua, "Presto/") {
+        return Engine::new("Presto", v);
+    }
+    if let Some(webkit) = webkit {
+        let chrome = token_version(ua, "Chrome/").or_else(|| token_version(ua, "Chromium/"));
+        return match chrome {
+            Some(v) if v.major.unwrap_or(0) >= BLINK_SINCE => Engine::new("Blink", v),
+            _ => Engine::new("WebKit", webkit),
+        };
+    }
+    if ua.contains("Gecko/") {
+        if let Some(v) = token_version(ua, "rv:") {
+            return Engine::new("Gecko", v);
+        }
+    }
+    Engine::default()
+}
+
+// the version that follows the `token`, as `Chrome/142.0.0.0`.
+// the token is compared in ASCII case insensitive, as `AppleWebkit/534.30` of `LG`.
+pub(crate) fn token_version(ua: &str, token: &str) -> Option<Version> {
+    let idx = find_ignore_case(ua, token)? + token.len();
+    let s = &ua[idx..];
+    let len = s
+        .bytes()
+        .take_while(|b| b.is_ascii_digit() || *b == b'.')
+        .count();
+    if len == 0 {
+        return None;
+    }
+    Some(Version::parse(s[..len].trim_end_matches('.')))
+}
+
+// true if `f` holds for all of the product names outside the comments,
+// as `Mozilla` of `Mozilla/5.0` and the bare `Mobile`.
+// the comments are in `()` and `[]`.
+pub(crate) fn all_products(ua: &str, f: impl Fn(&str) -> bool) -> bool {
+    let mut depth = 0usize;
+    let mut outside = String::new();
+    for c in ua.chars() {
+        match c {
+            '(' | '[' => {
+                depth += 1;
+                outside.push(' ');
+            }
+            ')' | ']' => depth = depth.saturating_sub(1),
+            _ if depth == 0 => outside.push(c),
+            _ => {}
+        }
+    }
+    outside
+        .split_whitespace()
+        .all(|product| f(product.split('/').next().unwrap_or_default()))
+}
+
+// the byte index of the ASCII `token` in `s`, in ASCII case insensitive
+fn find_ignore_case(s: &str, token: &str) -> Option<usize> {
+    let token = token.as_bytes();
+    if token.is_empty() {
+        return Some(0);
+    }
+    s.as_bytes()
+        .windows(token.len())
+        .position(|a| a.eq_ignore_ascii_case(token))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    fn eng(ua: &str) -> String {
+        let e = super::super::try_init().unwrap().parse(ua).engine;
+        format!("{} {}", e.name, e.version)
+    }
+    #[test]
+    fn test_desktop() {
+        assert_eq!(
+            eng("Mozilla/5.0 (X11; Ubuntu; Linux x86_64; rv:145.0) Gecko/20100101 Firefox/145.0"),
+            "Gecko 145.0"
+        );
+        assert_eq!(
+            eng("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/142.0.0.0 Safari/537.36 Edg/142.0.0.0"),
+            "Blink 142.0.0.0"
+        );
+        assert_eq!(
+            eng("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/130.0.0.0 Safari/537.36 OPR/115.0.0.0"),
+            "Blink 130.0.0.0"
+        );
+        assert_eq!(
+            eng("Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.5 Safari/605.1.15"),
+            "WebKit 605.1.15"
+        );
+        assert_eq!(
+            eng("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/70.0.3538.102 Safari/537.36 Edge/18.19045"),
+            "EdgeHTML 18.19045"
+        );
+        assert_eq!(
+            eng("Mozilla/5.0 (Windows NT 10.0; WOW64; Trident/7.0; rv:11.0) like Gecko"),
+            "Trident 7.0"
+        );
+        assert_eq!(
+            eng("Opera/9.80 (Windows NT 6.1; WOW64) Presto/2.12.388 Version/12.18"),
+            "Presto 2.12.388"
+        );
+        assert_eq!(eng(""), " ");
+    }
+    #[test]
+    fn test_ios() {
+        // chrome on iOS
+        assert_eq!(
+            eng("Mozilla/5.0 (iPhone; CPU iPhone OS 17_5 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) CriOS/126.0.6478.153 Mobile/15E148 Safari/604.1"),
+            "WebKit 605.1.15"
+        );
+        // firefox on iOS
+        assert_eq!(
+            eng("Mozilla/5.0 (iPad; CPU OS 17_5 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) FxiOS/127.0 Mobile/15E148 Safari/605.1.15"),
+            "WebKit 605.1.15"
+        );
+    }
+    #[test]
+    fn test_android() {
+        assert_eq!(
+            eng("Mozilla/5.0 (Linux; Android 14; SM-S928B) AppleWebKit/537.36 (KHTML, like Gecko) SamsungBrowser/25.0 Chrome/121.0.0.0 Mobile Safari/537.36"),
+            "Blink 121.0.0.0"
+        );
+        assert_eq!(
+            eng("Mozilla/5.0 (Android 15; Mobile; SM-G556B; rv:130.0) Gecko/130.0 Firefox/130.0"),
+            "Gecko 130.0"
+        );
+        // the old android browser
+        assert_eq!(
+            eng("Mozilla/5.0 (Linux; U; Android 4.0.3; ko-kr; LG-L160L Build/IML74K) AppleWebkit/534.30 (KHTML, like Gecko) Version/4.0 Mobile Safari/534.30"),
+            "WebKit 534.30"
+        );
+        assert_eq!(
+            eng("Mozilla/5.0 (Linux; U; Android 4.0.3; de-de; Galaxy S II Build/GRJ22) AppleWebKit/534.30 (KHTML, like Gecko) Version/4.0 Mobile Safari/534.30"),
+            "WebKit 534.30"
+        );
+    }
+    #[test]
+    fn test_token_version() {
+        assert_eq!(
+            token_version("a Chrome/142.0.1. b", "Chrome/")
+                .unwrap()
+                .to_string(),
+            "142.0.1"
+        );
+        assert!(token_version("a Chrome/ b", "Chrome/").is_none());
+        assert_eq!(
+            token_version("a AppleWebkit/534.30 b", "AppleWebKit/")
+                .unwrap()
+                .to_string(),
+            "534.30"
+        );
+    }
+}
@@ -0,0 +1,106 @@
+use super::RegexesError;
+
+/// The error of this crate.
+#[derive(Debug)]
+pub enum Error {
+    /// The json is not well-formed.
+    Json(serde_json::Error),
+    /// The json is well-formed, but the fields do not match the type.
+    Schema(serde_json::Error),
+    /// Loading or compiling the regexes failed.
+    Regexes(RegexesError),
+    /// A value is not valid.
+    Validation(String),
+}
+
+/// The result with [`Error`].
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    /// Returns true if the error is caused by the input,
+    /// as the bad json sent from the client.
+    /// Otherwise, it is an internal failure.
+    pub fn is_client_error(&self) -> bool {
+        match self {
+            Error::Json(_) | Error::Schema(_) | Error::Validation(_) => true,
+            Error::Regexes(_) => false,
+        }
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            // the cause is in `source()`, so that it is not printed twice
+            Error::Json(_) => write!(f, "invalid json"),
+            Error::Schema(_) => write!(f, "unexpected json data"),
+            Error::Regexes(_) => write!(f, "can not load the regexes"),
+            Error::Validation(s) => write!(f, "invalid value: {s}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Json(e) | Error::Schema(e) => Some(e),
+            Error::Regexes(e) => Some(e),
+            Error::Validation(_) => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        match e.classify() {
+            serde_json::error::Category::Data => Error::Schema(e),
+            _ => Error::Json(e),
+        }
+    }
+}
+
+impl From<RegexesError> for Error {
+    fn from(e: RegexesError) -> Self {
+        Error::Regexes(e)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{BroInfo, UserAgent};
+    use super::*;
+    #[test]
+    fn test_json() {
+        let r = BroInfo::from_json_str(r#"{"basic":"#);
+        let e = r.unwrap_err();
+        assert!(matches!(e, Error::Json(_)));
+        assert!(e.is_client_error());
+        //
+        let r = UserAgent::from_json_str(r#""Mozilla/5.0"#);
+        assert!(matches!(r, Err(Error::Json(_))));
+    }
+    #[test]
+    fn test_schema() {
+        let r = BroInfo::from_json_str(r#"{"basic":{"user_agent":""}}"#);
+        let e = r.unwrap_err();
+        assert!(matches!(e, Error::Schema(_)));
+        assert!(e.is_client_error());
+        assert!(std::error::Error::source(&e).is_some());
+        assert_eq!(e.to_string(), "unexpected json data");
+        //
+        let r = UserAgent::from_json_str(r#"123"#);
+        assert!(matches!(r, Err(Error::Schema(_))));
+    }
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn test_regexes() {
+        let e: Error = super::super::UaParser::builder()
+            .regexes_str("[")
+            .build()
+            .err()
+            .unwrap()
+            .into();
+        assert!(matches!(e, Error::Regexes(RegexesError::Yaml(_))));
+        assert!(!e.is_client_error());
+    }
+}
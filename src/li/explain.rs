@@ -0,0 +1,497 @@
+use super::{bundled, try_init, Browser, RegexesError, UaParser};
+use regex::bytes::{RegexSet, RegexSetBuilder};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::sync::OnceLock;
+use ua_parser::{device, os, user_agent};
+
+/// Explains how `user agent` is parsed by the shared parser.
+///
+/// See [`UaParser::explain()`].
+pub fn explain(ua: &str) -> std::result::Result<Explanation, RegexesError> {
+    Ok(try_init()?.explain(ua))
+}
+
+/// Where the rule comes from.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleSource {
+    /// `regexes.yaml`, the bundled one or the loaded one
+    #[default]
+    Regexes,
+    /// the overlay rules of [`UaParserBuilder`](super::UaParserBuilder)
+    Overlay,
+}
+
+/// The rule that matched in a stage.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct RuleMatch {
+    /// where the rule comes from
+    pub source: RuleSource,
+    /// the index in the list of the stage of the source, as `user_agent_parsers`
+    pub index: usize,
+    /// the regex pattern
+    pub regex: String,
+    /// the replacement templates of the rule, as `("family_replacement", "$1 Mobile")`.
+    /// The fields without the template are the match groups.
+    pub replacements: Vec<(String, String)>,
+}
+
+/// The explanation of a stage: the browser, the operating system or the device.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct Stage {
+    /// the rule that matched, or `None`
+    pub rule: Option<RuleMatch>,
+    /// the fields of `ua_parser`, before the post processing, as `("family", Some("Firefox"))`
+    pub raw: Vec<(String, Option<String>)>,
+}
+
+/// The explanation of the parsing of `user agent`.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct Explanation {
+    /// the stage of `user_agent_parsers`
+    pub user_agent: Stage,
+    /// the stage of `os_parsers`
+    pub os: Stage,
+    /// the stage of `device_parsers`
+    pub device: Stage,
+    /// the result of the extractor of the stages,
+    /// the same as [`UaParser::parse()`] without the lite parser
+    pub browser: Browser,
+}
+
+impl std::fmt::Display for Explanation {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let stages = [
+            ("user_agent_parsers", &self.user_agent),
+            ("os_parsers", &self.os),
+            ("device_parsers", &self.device),
+        ];
+        for (name, stage) in stages {
+            match &stage.rule {
+                Some(rule) => {
+                    let source = match rule.source {
+                        RuleSource::Regexes => "regexes",
+                        RuleSource::Overlay => "overlay",
+                    };
+                    writeln!(f, "{name}[{}] ({source}): {}", rule.index, rule.regex)?;
+                    for (field, template) in &rule.replacements {
+                        writeln!(f, "  {field}: {template}")?;
+                    }
+                }
+                None => writeln!(f, "{name}: no match")?,
+            }
+            for (field, value) in &stage.raw {
+                writeln!(f, "  = {field}: {}", value.as_deref().unwrap_or("-"))?;
+            }
+        }
+        write!(f, "{:?}", self.browser)
+    }
+}
+
+impl UaParser {
+    /// Explains how `user agent` is parsed, for debugging the rules.
+    ///
+    /// For each stage, the matched rule with its replacement templates,
+    /// and the fields of `ua_parser` before the post processing are returned.
+    /// The rules are compiled into a set of each stage at the first call.
+    pub fn explain(&self, ua: &str) -> Explanation {
+        let rules = self.sources.rule_sets();
+        let (browser, os, device) = self.extractor.extract(ua);
+        let user_agent = Stage {
+            rule: browser.as_ref().and_then(|_| rules.user_agent.find(ua)),
+            raw: browser
+                .as_ref()
+                .map(|a| {
+                    raw_fields(&[
+                        ("family", Some(a.family.as_ref())),
+                        ("major", a.major),
+                        ("minor", a.minor),
+                        ("patch", a.patch),
+                        ("patch_minor", a.patch_minor),
+                    ])
+                })
+                .unwrap_or_default(),
+        };
+        let os_stage = Stage {
+            rule: os.as_ref().and_then(|_| rules.os.find(ua)),
+            raw: os
+                .as_ref()
+                .map(|a| {
+                    raw_fields(&[
+                        ("os", Some(a.os.as_ref())),
+                        ("major", a.major.as_deref()),
+                        ("minor", a.minor.as_deref()),
+                        ("patch", a.patch.as_deref()),
+                        ("patch_minor", a.patch_minor.as_deref()),
+                    ])
+                })
+                .unwrap_or_default(),
+        };
+        let device_stage = Stage {
+            rule: device.as_ref().and_then(|_| rules.device.find(ua)),
+            raw: device
+                .as_ref()
+                .map(|a| {
+                    raw_fields(&[
+                        ("device", Some(a.device.as_ref())),
+                        ("brand", a.brand.as_deref()),
+                        ("model", a.model.as_deref()),
+                    ])
+                })
+                .unwrap_or_default(),
+        };
+        Explanation {
+            user_agent,
+            os: os_stage,
+            device: device_stage,
+            // the same result of the extractor as the stages
+            browser: self.convert_extracted(ua, (browser, os, device)),
+        }
+    }
+}
+
+fn raw_fields(fields: &[(&str, Option<&str>)]) -> Vec<(String, Option<String>)> {
+    fields
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.map(|a| a.to_string())))
+        .collect()
+}
+
+/// The rule sources that `explain` looks up, besides the bundled ones.
+///
+/// The bundled rules are the static tables, so that they are not kept here.
+/// The rule sets are compiled at the first `explain`.
+pub(crate) struct RuleSources {
+    // the overlay rules, ahead of `regexes`
+    overlay: ua_parser::Regexes<'static>,
+    // the loaded `regexes.yaml`, or `None` for the bundled one
+    regexes: Option<ua_parser::Regexes<'static>>,
+    rule_sets: OnceLock<RuleSets>,
+}
+
+impl RuleSources {
+    pub(crate) fn new(
+        overlay: &ua_parser::Regexes<'static>,
+        regexes: Option<&ua_parser::Regexes<'static>>,
+    ) -> Self {
+        Self {
+            overlay: copy_regexes(overlay),
+            regexes: regexes.map(copy_regexes),
+            rule_sets: OnceLock::new(),
+        }
+    }
+    fn rule_sets(&self) -> &RuleSets {
+        self.rule_sets.get_or_init(|| {
+            let bundled_regexes;
+            let regexes = match &self.regexes {
+                Some(a) => a,
+                None => {
+                    bundled_regexes = bundled::regexes();
+                    &bundled_regexes
+                }
+            };
+            let overlay = &self.overlay;
+            RuleSets {
+                user_agent: RuleSet::new(&overlay.user_agent_parsers, &regexes.user_agent_parsers),
+                os: RuleSet::new(&overlay.os_parsers, &regexes.os_parsers),
+                device: RuleSet::new(&overlay.device_parsers, &regexes.device_parsers),
+            }
+        })
+    }
+}
+
+fn copy_regexes(regexes: &ua_parser::Regexes<'static>) -> ua_parser::Regexes<'static> {
+    ua_parser::Regexes {
+        user_agent_parsers: regexes.user_agent_parsers.iter().map(Rule::copy).collect(),
+        os_parsers: regexes.os_parsers.iter().map(Rule::copy).collect(),
+        device_parsers: regexes.device_parsers.iter().map(Rule::copy).collect(),
+    }
+}
+
+// the rule sets of the stages
+struct RuleSets {
+    user_agent: RuleSet,
+    os: RuleSet,
+    device: RuleSet,
+}
+
+// the rules of a stage, the overlay rules are ahead of `regexes.yaml`,
+// and their regexes compiled into a set.
+struct RuleSet {
+    rules: Vec<RuleMatch>,
+    // `None` if the set could not be compiled
+    set: Option<RegexSet>,
+}
+
+impl RuleSet {
+    fn new<R: Rule>(overlay: &[R], regexes: &[R]) -> Self {
+        let overlay = overlay.iter().enumerate().map(|a| (RuleSource::Overlay, a));
+        let regexes = regexes.iter().enumerate().map(|a| (RuleSource::Regexes, a));
+        let (patterns, rules): (Vec<_>, Vec<_>) = overlay
+            .chain(regexes)
+            .map(|(source, (index, rule))| {
+                let rule_match = RuleMatch {
+                    source,
+                    index,
+                    regex: rule.regex().to_string(),
+                    replacements: rule.replacements(),
+                };
+                (unbounded(&rule.pattern()), rule_match)
+            })
+            .unzip();
+        // the character classes in ASCII, as `ua_parser` does
+        let set = RegexSetBuilder::new(patterns)
+            .unicode(false)
+            .size_limit(SIZE_LIMIT)
+            .dfa_size_limit(SIZE_LIMIT)
+            .build()
+            .ok();
+        Self { rules, set }
+    }
+    // the first rule that matches
+    fn find(&self, ua: &str) -> Option<RuleMatch> {
+        let idx = self.set.as_ref()?.matches(ua.as_bytes()).iter().next()?;
+        Some(self.rules[idx].clone())
+    }
+}
+
+// the set of all the rules of a stage is larger than the default limit
+const SIZE_LIMIT: usize = 256 << 20;
+
+// the large repetitions, as `{0,100}` and `{1,300}`, into `*` and `+`,
+// as `ua_parser` does, so that the set matches the same user agents.
+fn unbounded(re: &str) -> String {
+    let mut out = String::with_capacity(re.len());
+    let (mut escape, mut class) = (false, 0usize);
+    let mut rest = re;
+    while let Some(c) = rest.chars().next() {
+        if c == '{' && !escape && class == 0 && !out.is_empty() {
+            if let Some(end) = rest.find('}') {
+                let range = &rest[1..end];
+                if let Some((start @ ("0" | "1"), max)) = range.split_once(',') {
+                    if max.len() > 2 && max.bytes().all(|b| b.is_ascii_digit()) {
+                        out.push(if start == "0" { '*' } else { '+' });
+                        rest = &rest[end + 1..];
+                        continue;
+                    }
+                }
+            }
+        }
+        match c {
+            '[' if !escape => class += 1,
+            ']' if !escape => class = class.saturating_sub(1),
+            _ => {}
+        }
+        escape = c == '\\' && !escape;
+        out.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+    out
+}
+
+/// A rule of a stage of `regexes.yaml`.
+trait Rule: Sized {
+    fn copy(&self) -> Self;
+    fn regex(&self) -> &str;
+    fn replacements(&self) -> Vec<(String, String)>;
+    /// The pattern in the set, with the flags of the rule.
+    fn pattern(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self.regex())
+    }
+}
+
+fn templates(fields: &[(&str, &Option<Cow<str>>)]) -> Vec<(String, String)> {
+    fields
+        .iter()
+        .filter_map(|(name, value)| value.as_ref().map(|a| (name.to_string(), a.to_string())))
+        .collect()
+}
+
+impl Rule for user_agent::Parser<'static> {
+    fn copy(&self) -> Self {
+        Self {
+            regex: self.regex.clone(),
+            family_replacement: self.family_replacement.clone(),
+            v1_replacement: self.v1_replacement.clone(),
+            v2_replacement: self.v2_replacement.clone(),
+            v3_replacement: self.v3_replacement.clone(),
+            v4_replacement: self.v4_replacement.clone(),
+        }
+    }
+    fn regex(&self) -> &str {
+        &self.regex
+    }
+    fn replacements(&self) -> Vec<(String, String)> {
+        templates(&[
+            ("family_replacement", &self.family_replacement),
+            ("v1_replacement", &self.v1_replacement),
+            ("v2_replacement", &self.v2_replacement),
+            ("v3_replacement", &self.v3_replacement),
+            ("v4_replacement", &self.v4_replacement),
+        ])
+    }
+}
+
+impl Rule for os::Parser<'static> {
+    fn copy(&self) -> Self {
+        Self {
+            regex: self.regex.clone(),
+            os_replacement: self.os_replacement.clone(),
+            os_v1_replacement: self.os_v1_replacement.clone(),
+            os_v2_replacement: self.os_v2_replacement.clone(),
+            os_v3_replacement: self.os_v3_replacement.clone(),
+            os_v4_replacement: self.os_v4_replacement.clone(),
+        }
+    }
+    fn regex(&self) -> &str {
+        &self.regex
+    }
+    fn replacements(&self) -> Vec<(String, String)> {
+        templates(&[
+            ("os_replacement", &self.os_replacement),
+            ("os_v1_replacement", &self.os_v1_replacement),
+            ("os_v2_replacement", &self.os_v2_replacement),
+            ("os_v3_replacement", &self.os_v3_replacement),
+            ("os_v4_replacement", &self.os_v4_replacement),
+        ])
+    }
+}
+
+impl Rule for device::Parser<'static> {
+    fn copy(&self) -> Self {
+        Self {
+            regex: self.regex.clone(),
+            regex_flag: self.regex_flag.as_ref().map(|_| device::Flag::IgnoreCase),
+            device_replacement: self.device_replacement.clone(),
+            brand_replacement: self.brand_replacement.clone(),
+            model_replacement: self.model_replacement.clone(),
+        }
+    }
+    fn regex(&self) -> &str {
+        &self.regex
+    }
+    fn replacements(&self) -> Vec<(String, String)> {
+        templates(&[
+            ("device_replacement", &self.device_replacement),
+            ("brand_replacement", &self.brand_replacement),
+            ("model_replacement", &self.model_replacement),
+        ])
+    }
+    fn pattern(&self) -> Cow<'_, str> {
+        match self.regex_flag {
+            Some(_) => Cow::Owned(format!("(?i:{})", self.regex)),
+            None => Cow::Borrowed(&self.regex),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_unbounded() {
+        assert_eq!(unbounded(r"a.{0,100}b.{1,300}c"), r"a.*b.+c");
+        assert_eq!(unbounded(r"a{0,99}b{2,300}"), r"a{0,99}b{2,300}");
+        assert_eq!(unbounded(r"\{0,100}[{0,100}]"), r"\{0,100}[{0,100}]");
+    }
+    #[test]
+    fn test_explain() {
+        let ex = explain(
+            "Mozilla/5.0 (Linux; Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/142.0.0.0 Mobile Safari/537.36",
+        )
+        .unwrap();
+        let rule = ex.user_agent.rule.as_ref().unwrap();
+        assert_eq!(rule.source, RuleSource::Regexes);
+        assert!(rule.regex.contains("Chrome"), "{}", rule.regex);
+        assert_eq!(
+            ex.user_agent.raw[0],
+            ("family".into(), Some("Chrome Mobile".into()))
+        );
+        assert_eq!(ex.os.raw[0], ("os".into(), Some("Android".into())));
+        assert_eq!(
+            ex.device.raw[1],
+            ("brand".into(), Some("Generic_Android".into()))
+        );
+        assert_eq!(ex.browser.name, "Chrome Mobile");
+        // the found rule is the one of the extractor
+        let regexes = super::super::bundled::regexes();
+        let family = regexes.user_agent_parsers[rule.index]
+            .family_replacement
+            .as_deref();
+        assert_eq!(family, Some("Chrome Mobile"));
+        assert!(ex.to_string().starts_with("user_agent_parsers["));
+    }
+    #[test]
+    fn test_same_rule() {
+        let uas = [
+            "Mozilla/5.0 (X11; Ubuntu; Linux x86_64; rv:145.0) Gecko/20100101 Firefox/145.0",
+            "Mozilla/5.0 (iPhone; CPU iPhone OS 17_5 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.5 Mobile/15E148 Safari/604.1",
+            "Mozilla/5.0 (Linux; Android 14; SM-S928B) AppleWebKit/537.36 (KHTML, like Gecko) SamsungBrowser/25.0 Chrome/121.0.0.0 Mobile Safari/537.36",
+            "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/142.0.0.0 Safari/537.36 Edg/142.0.0.0",
+            "Mozilla/5.0 (compatible; Googlebot/2.1; +http://www.google.com/bot.html)",
+        ];
+        for ua in uas {
+            let ex = explain(ua).unwrap();
+            // the extractor of only the found rule gives the same fields
+            let mut regexes = super::super::bundled::regexes();
+            let index = |stage: &Stage| stage.rule.as_ref().map(|a| a.index);
+            let single = ua_parser::Extractor::try_from(ua_parser::Regexes {
+                user_agent_parsers: index(&ex.user_agent)
+                    .map(|i| regexes.user_agent_parsers.swap_remove(i))
+                    .into_iter()
+                    .collect(),
+                os_parsers: index(&ex.os)
+                    .map(|i| regexes.os_parsers.swap_remove(i))
+                    .into_iter()
+                    .collect(),
+                device_parsers: index(&ex.device)
+                    .map(|i| regexes.device_parsers.swap_remove(i))
+                    .into_iter()
+                    .collect(),
+            })
+            .unwrap();
+            let (browser, os, device) = single.extract(ua);
+            let first = |stage: &Stage| stage.raw.first().and_then(|a| a.1.clone());
+            let browser = browser.map(|a| a.family.to_string());
+            assert_eq!(first(&ex.user_agent), browser, "{ua}");
+            assert_eq!(first(&ex.os), os.map(|a| a.os.to_string()), "{ua}");
+            assert_eq!(
+                first(&ex.device),
+                device.map(|a| a.device.to_string()),
+                "{ua}"
+            );
+        }
+    }
+    #[test]
+    fn test_no_match() {
+        let ex = explain("").unwrap();
+        assert_eq!(ex.user_agent.rule, None);
+        assert!(ex.user_agent.raw.is_empty());
+        assert_eq!(ex.os.rule, None);
+    }
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn test_overlay() {
+        let parser = UaParser::builder()
+            .overlay_str(concat!(
+                "user_agent_parsers:\n",
+                "  - regex: '(MyApp)/(\\d+)\\.(\\d+)'\n",
+                "    family_replacement: 'My $1'\n",
+            ))
+            .build()
+            .unwrap();
+        let ex = parser.explain("MyApp/1.2");
+        let rule = ex.user_agent.rule.unwrap();
+        assert_eq!((rule.source, rule.index), (RuleSource::Overlay, 0));
+        assert_eq!(
+            rule.replacements,
+            vec![("family_replacement".into(), "My $1".into())]
+        );
+        assert_eq!(
+            ex.user_agent.raw[0],
+            ("family".into(), Some("My MyApp".into()))
+        );
+        assert_eq!(ex.user_agent.raw[1], ("major".into(), Some("1".into())));
+    }
+}
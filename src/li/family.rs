@@ -0,0 +1,434 @@
+use super::{Browser, Os};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::borrow::Cow;
+
+/// The canonical browser family.
+///
+/// The many family names of `regexes.yaml` are mapped onto this,
+/// as "Chrome Mobile WebView" is [`BrowserFamily::Chrome`] with the variant flags.
+/// It is (de)serialized as the stable snake_case id, as `samsung_internet`,
+/// and [`BrowserFamily::Other`] as the name in the `other:` namespace, as `other:Pale Moon`,
+/// so that it is not taken as the known one, as `other:chrome`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub enum BrowserFamily {
+    /// not detected
+    #[default]
+    Unknown,
+    /// Google Chrome
+    Chrome,
+    /// Chromium
+    Chromium,
+    /// Microsoft Edge
+    Edge,
+    /// Mozilla Firefox
+    Firefox,
+    /// Apple Safari
+    Safari,
+    /// Opera
+    Opera,
+    /// Opera Mini
+    OperaMini,
+    /// Samsung Internet
+    SamsungInternet,
+    /// UC Browser
+    UcBrowser,
+    /// Yandex Browser
+    Yandex,
+    /// Vivaldi
+    Vivaldi,
+    /// Brave
+    Brave,
+    /// DuckDuckGo
+    DuckDuckGo,
+    /// Internet Explorer
+    InternetExplorer,
+    /// the stock Android browser
+    AndroidBrowser,
+    /// Amazon Silk
+    Silk,
+    /// the other family, as the name
+    Other(String),
+}
+
+/// The variant flags of the browser family.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BrowserVariant {
+    /// the mobile browser, as "Chrome Mobile"
+    pub mobile: bool,
+    /// the embedded browser of an app, as "Chrome Mobile WebView"
+    pub webview: bool,
+    /// the pre-release channel, as "Firefox Beta"
+    pub beta: bool,
+}
+
+/// The canonical operating system family.
+///
+/// It is (de)serialized as the stable snake_case id, as `mac_os`,
+/// and [`OsFamily::Other`] as the name in the `other:` namespace, as `other:PlayStation`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub enum OsFamily {
+    /// not detected
+    #[default]
+    Unknown,
+    /// Microsoft Windows
+    Windows,
+    /// Windows Phone
+    WindowsPhone,
+    /// Apple macOS, "Mac OS X"
+    MacOs,
+    /// Apple iOS
+    Ios,
+    /// Apple iPadOS
+    IpadOs,
+    /// Android
+    Android,
+    /// ChromeOS
+    ChromeOs,
+    /// Linux and its distributions
+    Linux,
+    /// FreeBSD, OpenBSD and NetBSD
+    Bsd,
+    /// HarmonyOS
+    HarmonyOs,
+    /// KaiOS
+    KaiOs,
+    /// Tizen
+    Tizen,
+    /// BlackBerry OS
+    BlackBerry,
+    /// the other family, as the name
+    Other(String),
+}
+
+// the family names of `regexes.yaml`, and the mobile and webview flags
+const BROWSER_NAMES: &[(&str, BrowserFamily, bool, bool)] = &[
+    ("Chrome", BrowserFamily::Chrome, false, false),
+    ("HeadlessChrome", BrowserFamily::Chrome, false, false),
+    ("Chrome Mobile", BrowserFamily::Chrome, true, false),
+    ("Chrome Mobile WebView", BrowserFamily::Chrome, true, true),
+    ("Chrome Mobile iOS", BrowserFamily::Chrome, true, false),
+    ("Chromium", BrowserFamily::Chromium, false, false),
+    ("Edge", BrowserFamily::Edge, false, false),
+    ("Edge Mobile", BrowserFamily::Edge, true, false),
+    ("Firefox", BrowserFamily::Firefox, false, false),
+    ("Firefox Mobile", BrowserFamily::Firefox, true, false),
+    ("Firefox iOS", BrowserFamily::Firefox, true, false),
+    ("Safari", BrowserFamily::Safari, false, false),
+    ("Mobile Safari", BrowserFamily::Safari, true, false),
+    (
+        "Mobile Safari UI/WKWebView",
+        BrowserFamily::Safari,
+        true,
+        true,
+    ),
+    ("Opera", BrowserFamily::Opera, false, false),
+    ("Opera Mobile", BrowserFamily::Opera, true, false),
+    ("Opera Mini", BrowserFamily::OperaMini, true, false),
+    (
+        "Samsung Internet",
+        BrowserFamily::SamsungInternet,
+        true,
+        false,
+    ),
+    ("UC Browser", BrowserFamily::UcBrowser, true, false),
+    ("Yandex Browser", BrowserFamily::Yandex, false, false),
+    ("Vivaldi", BrowserFamily::Vivaldi, false, false),
+    ("Brave", BrowserFamily::Brave, false, false),
+    ("DuckDuckGo Mobile", BrowserFamily::DuckDuckGo, true, false),
+    ("IE", BrowserFamily::InternetExplorer, false, false),
+    ("IE Mobile", BrowserFamily::InternetExplorer, true, false),
+    ("Android", BrowserFamily::AndroidBrowser, true, false),
+    ("Amazon Silk", BrowserFamily::Silk, true, false),
+];
+
+// the suffixes of the pre-release channels
+const BETA_SUFFIXES: &[&str] = &[" Beta", " Dev", " Canary", " Nightly", " Alpha"];
+
+impl BrowserFamily {
+    /// Maps the family name of `regexes.yaml`, as "Chrome Mobile WebView".
+    pub fn from_name(name: &str) -> (Self, BrowserVariant) {
+        if name.is_empty() || name == "Other" {
+            return (Self::Unknown, BrowserVariant::default());
+        }
+        let base = BETA_SUFFIXES
+            .iter()
+            .find_map(|a| name.strip_suffix(a))
+            .unwrap_or(name);
+        let beta = base.len() != name.len();
+        let lookup = |s: &str| BROWSER_NAMES.iter().find(|(a, ..)| *a == s);
+        if let Some((_, family, mobile, webview)) = lookup(base) {
+            let variant = BrowserVariant {
+                mobile: *mobile,
+                webview: *webview,
+                beta,
+            };
+            return (family.clone(), variant);
+        }
+        // as "Vivaldi Mobile"
+        if let Some((_, family, _, webview)) = base.strip_suffix(" Mobile").and_then(lookup) {
+            let variant = BrowserVariant {
+                mobile: true,
+                webview: *webview,
+                beta,
+            };
+            return (family.clone(), variant);
+        }
+        (Self::Other(name.to_string()), BrowserVariant::default())
+    }
+    /// Returns the stable snake_case id, as `samsung_internet`, or `other:Pale Moon`.
+    pub fn id(&self) -> Cow<'_, str> {
+        let id = match self {
+            Self::Unknown => "unknown",
+            Self::Chrome => "chrome",
+            Self::Chromium => "chromium",
+            Self::Edge => "edge",
+            Self::Firefox => "firefox",
+            Self::Safari => "safari",
+            Self::Opera => "opera",
+            Self::OperaMini => "opera_mini",
+            Self::SamsungInternet => "samsung_internet",
+            Self::UcBrowser => "uc_browser",
+            Self::Yandex => "yandex",
+            Self::Vivaldi => "vivaldi",
+            Self::Brave => "brave",
+            Self::DuckDuckGo => "duckduckgo",
+            Self::InternetExplorer => "internet_explorer",
+            Self::AndroidBrowser => "android_browser",
+            Self::Silk => "silk",
+            Self::Other(s) => return format!("{OTHER}{s}").into(),
+        };
+        id.into()
+    }
+    fn from_id(s: &str) -> Self {
+        if let Some(name) = s.strip_prefix(OTHER) {
+            return Self::Other(name.to_string());
+        }
+        match s {
+            "unknown" => Self::Unknown,
+            "chrome" => Self::Chrome,
+            "chromium" => Self::Chromium,
+            "edge" => Self::Edge,
+            "firefox" => Self::Firefox,
+            "safari" => Self::Safari,
+            "opera" => Self::Opera,
+            "opera_mini" => Self::OperaMini,
+            "samsung_internet" => Self::SamsungInternet,
+            "uc_browser" => Self::UcBrowser,
+            "yandex" => Self::Yandex,
+            "vivaldi" => Self::Vivaldi,
+            "brave" => Self::Brave,
+            "duckduckgo" => Self::DuckDuckGo,
+            "internet_explorer" => Self::InternetExplorer,
+            "android_browser" => Self::AndroidBrowser,
+            "silk" => Self::Silk,
+            s => Self::Other(s.to_string()),
+        }
+    }
+}
+
+impl OsFamily {
+    /// Maps the operating system name of `regexes.yaml`, as "Mac OS X".
+    pub fn from_name(name: &str) -> Self {
+        match name {
+            "" | "Other" => Self::Unknown,
+            "Windows Phone" | "Windows Mobile" => Self::WindowsPhone,
+            "Mac OS X" | "macOS" | "Mac OS" => Self::MacOs,
+            "iOS" => Self::Ios,
+            "iPadOS" => Self::IpadOs,
+            "Android" => Self::Android,
+            "Chrome OS" | "ChromeOS" => Self::ChromeOs,
+            "FreeBSD" | "OpenBSD" | "NetBSD" => Self::Bsd,
+            "HarmonyOS" => Self::HarmonyOs,
+            "KaiOS" => Self::KaiOs,
+            "Tizen" => Self::Tizen,
+            "BlackBerry OS" | "BlackBerry Tablet OS" => Self::BlackBerry,
+            "Linux" | "Ubuntu" | "Kubuntu" | "Debian" | "Fedora" | "Red Hat" | "CentOS"
+            | "Mint" | "Linux Mint" | "Arch Linux" | "Gentoo" | "SUSE" | "openSUSE"
+            | "Slackware" | "Mandriva" => Self::Linux,
+            s if s.starts_with("Windows") => Self::Windows,
+            s => Self::Other(s.to_string()),
+        }
+    }
+    /// Returns the stable snake_case id, as `mac_os`, or `other:PlayStation`.
+    pub fn id(&self) -> Cow<'_, str> {
+        let id = match self {
+            Self::Unknown => "unknown",
+            Self::Windows => "windows",
+            Self::WindowsPhone => "windows_phone",
+            Self::MacOs => "mac_os",
+            Self::Ios => "ios",
+            Self::IpadOs => "ipad_os",
+            Self::Android => "android",
+            Self::ChromeOs => "chrome_os",
+            Self::Linux => "linux",
+            Self::Bsd => "bsd",
+            Self::HarmonyOs => "harmony_os",
+            Self::KaiOs => "kai_os",
+            Self::Tizen => "tizen",
+            Self::BlackBerry => "blackberry",
+            Self::Other(s) => return format!("{OTHER}{s}").into(),
+        };
+        id.into()
+    }
+    fn from_id(s: &str) -> Self {
+        if let Some(name) = s.strip_prefix(OTHER) {
+            return Self::Other(name.to_string());
+        }
+        match s {
+            "unknown" => Self::Unknown,
+            "windows" => Self::Windows,
+            "windows_phone" => Self::WindowsPhone,
+            "mac_os" => Self::MacOs,
+            "ios" => Self::Ios,
+            "ipad_os" => Self::IpadOs,
+            "android" => Self::Android,
+            "chrome_os" => Self::ChromeOs,
+            "linux" => Self::Linux,
+            "bsd" => Self::Bsd,
+            "harmony_os" => Self::HarmonyOs,
+            "kai_os" => Self::KaiOs,
+            "tizen" => Self::Tizen,
+            "blackberry" => Self::BlackBerry,
+            s => Self::Other(s.to_string()),
+        }
+    }
+}
+
+// the namespace of the id of the other family
+const OTHER: &str = "other:";
+
+macro_rules! ImplFamilyId {
+    ($ty: ident) => {
+        impl std::fmt::Display for $ty {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "{}", self.id())
+            }
+        }
+
+        impl std::str::FromStr for $ty {
+            type Err = std::convert::Infallible;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok(Self::from_id(s))
+            }
+        }
+
+        impl Serialize for $ty {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(&self.id())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $ty {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let s = String::deserialize(deserializer)?;
+                Ok(Self::from_id(&s))
+            }
+        }
+    };
+}
+
+ImplFamilyId!(BrowserFamily);
+ImplFamilyId!(OsFamily);
+
+impl Browser {
+    /// Returns the canonical family of `name`.
+    pub fn family(&self) -> BrowserFamily {
+        BrowserFamily::from_name(&self.name).0
+    }
+    /// Returns the variant flags of `name`, as mobile or webview.
+    pub fn variant(&self) -> BrowserVariant {
+        BrowserFamily::from_name(&self.name).1
+    }
+}
+
+impl Os {
+    /// Returns the canonical family of `name`.
+    pub fn family(&self) -> OsFamily {
+        OsFamily::from_name(&self.name)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_browser_family() {
+        let (family, variant) = BrowserFamily::from_name("Chrome Mobile WebView");
+        assert_eq!(family, BrowserFamily::Chrome);
+        assert!(variant.mobile && variant.webview && !variant.beta);
+        let (family, variant) = BrowserFamily::from_name("Firefox Beta");
+        assert_eq!(family, BrowserFamily::Firefox);
+        assert!(!variant.mobile && variant.beta);
+        let (family, variant) = BrowserFamily::from_name("Vivaldi Mobile");
+        assert_eq!(family, BrowserFamily::Vivaldi);
+        assert!(variant.mobile);
+        assert_eq!(
+            BrowserFamily::from_name("Mobile Safari UI/WKWebView").0,
+            BrowserFamily::Safari
+        );
+        assert_eq!(BrowserFamily::from_name("Other").0, BrowserFamily::Unknown);
+        assert_eq!(
+            BrowserFamily::from_name("Pale Moon").0,
+            BrowserFamily::Other("Pale Moon".to_string())
+        );
+    }
+    #[test]
+    fn test_os_family() {
+        assert_eq!(OsFamily::from_name("Mac OS X"), OsFamily::MacOs);
+        assert_eq!(OsFamily::from_name("Windows"), OsFamily::Windows);
+        assert_eq!(OsFamily::from_name("Windows Phone"), OsFamily::WindowsPhone);
+        assert_eq!(OsFamily::from_name("Ubuntu"), OsFamily::Linux);
+        assert_eq!(OsFamily::from_name(""), OsFamily::Unknown);
+        assert_eq!(
+            OsFamily::from_name("PlayStation"),
+            OsFamily::Other("PlayStation".to_string())
+        );
+    }
+    #[test]
+    fn test_serde() {
+        let s = serde_json::to_string(&BrowserFamily::SamsungInternet).unwrap();
+        assert_eq!(s, r#""samsung_internet""#);
+        let s = serde_json::to_string(&OsFamily::MacOs).unwrap();
+        assert_eq!(s, r#""mac_os""#);
+        let family: BrowserFamily = serde_json::from_str(r#""opera_mini""#).unwrap();
+        assert_eq!(family, BrowserFamily::OperaMini);
+        let family: OsFamily = "PlayStation".parse().unwrap();
+        assert_eq!(family.to_string(), "other:PlayStation");
+        let s = serde_json::to_string(&BrowserVariant::default()).unwrap();
+        assert_eq!(s, r#"{"mobile":false,"webview":false,"beta":false}"#);
+    }
+    #[test]
+    fn test_serde_other() {
+        let family = BrowserFamily::Other("Pale Moon".to_string());
+        let s = serde_json::to_string(&family).unwrap();
+        assert_eq!(s, r#""other:Pale Moon""#);
+        assert_eq!(serde_json::from_str::<BrowserFamily>(&s).unwrap(), family);
+        // the other family of the known id is not the known one
+        for name in ["chrome", "unknown", "other:chrome"] {
+            let family = BrowserFamily::Other(name.to_string());
+            let s = serde_json::to_string(&family).unwrap();
+            assert_eq!(serde_json::from_str::<BrowserFamily>(&s).unwrap(), family);
+            let family = OsFamily::Other(name.to_string());
+            let s = serde_json::to_string(&family).unwrap();
+            assert_eq!(serde_json::from_str::<OsFamily>(&s).unwrap(), family);
+        }
+        let family: BrowserFamily = serde_json::from_str(r#""chrome""#).unwrap();
+        assert_eq!(family, BrowserFamily::Chrome);
+        let family: OsFamily = "android".parse().unwrap();
+        assert_eq!(family.to_string().parse::<OsFamily>().unwrap(), family);
+    }
+    #[test]
+    fn test_browser() {
+        let browser = Browser {
+            name: "Chrome Mobile".to_string(),
+            os: Some(Os {
+                name: "Android".to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert_eq!(browser.family(), BrowserFamily::Chrome);
+        assert!(browser.variant().mobile);
+        assert_eq!(browser.os.unwrap().family(), OsFamily::Android);
+    }
+}
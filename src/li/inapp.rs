@@ -0,0 +1,62 @@
+use super::engine::token_version;
+use super::Version;
+use serde::{Deserialize, Serialize};
+
+// The tokens of the in-app browsers, as `(name, token, version token)`,
+// that are converted from `resources/in_apps.yaml` by `build.rs`.
+include!(concat!(env!("OUT_DIR"), "/in_apps.rs"));
+
+/// The host app of the in-app browser, as `Instagram` or `WeChat`.
+///
+/// The in-app browsers often break the `OAuth` popups and the downloads,
+/// so that the page can tell the user to open it in the browser.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct InApp {
+    /// a host app name
+    pub name: String,
+    /// a host app version, empty if the app does not tell it
+    pub version: Version,
+}
+
+/// Finds the host app of the in-app browser with the tokens of `user agent`.
+pub(crate) fn detect_in_app(ua: &str) -> Option<InApp> {
+    let (name, _, version) = IN_APPS.iter().find(|(_, token, _)| ua.contains(token))?;
+    Some(InApp {
+        name: name.to_string(),
+        version: token_version(ua, version).unwrap_or_default(),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    fn in_app(ua: &str) -> String {
+        match detect_in_app(ua) {
+            Some(a) => format!("{} {}", a.name, a.version),
+            None => String::new(),
+        }
+    }
+    #[test]
+    fn test_detect() {
+        let ua = "Mozilla/5.0 (iPhone; CPU iPhone OS 17_5 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Mobile/15E148 [FBAN/FBIOS;FBAV/468.0.0.50.106;FBBV/612345678;FBDV/iPhone15,2;FBMD/iPhone;FBSN/iOS;FBSV/17.5;FBSS/3;FBCR/;FBID/phone;FBLC/en_US;FBOP/5]";
+        assert_eq!(in_app(ua), "Facebook 468.0.0.50.106");
+        let ua = "Mozilla/5.0 (Linux; Android 14; Pixel 8 Build/AP2A.240805.005; wv) AppleWebKit/537.36 (KHTML, like Gecko) Version/4.0 Chrome/127.0.6533.103 Mobile Safari/537.36 [FB_IAB/FB4A;FBAV/477.0.0.49.80;]";
+        assert_eq!(in_app(ua), "Facebook 477.0.0.49.80");
+        let ua = "Mozilla/5.0 (Linux; Android 14; SM-S918B Build/UP1A.231005.007; wv) AppleWebKit/537.36 (KHTML, like Gecko) Version/4.0 Chrome/127.0.6533.103 Mobile Safari/537.36 Instagram 343.0.0.33.101 Android (34/14; 480dpi; 1080x2316; samsung; SM-S918B; dm3q; qcom; en_US; 628213426)";
+        assert_eq!(in_app(ua), "Instagram 343.0.0.33.101");
+        let ua = "Mozilla/5.0 (iPhone; CPU iPhone OS 17_5 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Mobile/15E148 Safari Line/14.10.0";
+        assert_eq!(in_app(ua), "LINE 14.10.0");
+        let ua = "Mozilla/5.0 (iPhone; CPU iPhone OS 17_5 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Mobile/15E148 MicroMessenger/8.0.49(0x18003137) NetType/WIFI Language/zh_CN";
+        assert_eq!(in_app(ua), "WeChat 8.0.49");
+        let ua = "Mozilla/5.0 (Linux; Android 13; Pixel 7 Build/TQ3A.230901.001; wv) AppleWebKit/537.36 (KHTML, like Gecko) Version/4.0 Chrome/116.0.0.0 Mobile Safari/537.36 trill_2023605030 JsSdk/1.0 NetType/WIFI Channel/googleplay AppName/trill app_version/36.5.3 ByteLocale/en";
+        assert_eq!(in_app(ua), "TikTok 36.5.3");
+        let ua = "Mozilla/5.0 (Linux; Android 12; Pixel 6) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Mobile Safari/537.36 TwitterAndroid";
+        assert_eq!(in_app(ua), "X ");
+        let ua = "Mozilla/5.0 (iPhone; CPU iPhone OS 11_2_1 like Mac OS X) AppleWebKit/604.4.7 (KHTML, like Gecko) Mobile/15C153 Twitter for iPhone/7.19";
+        assert_eq!(in_app(ua), "X 7.19");
+        let ua = "Mozilla/5.0 (iPad; U; CPU OS 4_3_5 like Mac OS X; en-us) AppleWebKit/533.17.9 (KHTML, like Gecko) Mobile/8L1 Twitter for iPad";
+        assert_eq!(in_app(ua), "X ");
+        let ua = "Mozilla/5.0 (iPhone; CPU iPhone OS 17_5 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.5 Mobile/15E148 Safari/604.1";
+        assert_eq!(in_app(ua), "");
+    }
+}
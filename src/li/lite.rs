@@ -0,0 +1,324 @@
+use super::architecture::detect_architecture;
+use super::deviceclass::classify;
+use super::engine::{all_products, detect_engine, token_version};
+use super::inapp::detect_in_app;
+use super::osversion::is_exact;
+use super::runtime::detect_runtime;
+use super::{Browser, Client, ClientKind, Device, Os, Version};
+
+// The lightweight parser that finds the tokens of the major browsers,
+// without the regexes of `regexes.yaml`.
+//
+// The names are the same as the family names of `regexes.yaml`.
+
+/// Parses `user agent` with the tokens.
+///
+/// Returns `None` if the browser or the operating system is not found,
+/// or if there is a product token that this does not know,
+/// as `YaBrowser/` or `Vivaldi/` that is with `Chrome/`.
+#[cfg_attr(not(feature = "extractor"), allow(dead_code))]
+pub(crate) fn parse(ua: &str) -> Option<Browser> {
+    let (browser, found) = parse_partial(ua);
+    (found && has_known_products(ua)).then_some(browser)
+}
+
+/// Parses `user agent` with the tokens, and returns what is found,
+/// and true if both the browser and the operating system are found.
+pub(crate) fn parse_partial(ua: &str) -> (Browser, bool) {
+    let os = detect_os(ua);
+    let browser = detect_browser(ua, &os.name);
+    let found = browser.is_some() && !os.name.is_empty();
+    let (name, version) = browser.unwrap_or_default();
+    let device = detect_device(ua, &os.name);
+    let device_class = classify(ua, &os.name, &device);
+    let engine = detect_engine(ua, &os.name);
+    let architecture = detect_architecture(ua, &os.name, &os.version);
+    let runtime = detect_runtime(ua, &name, &os.name);
+    let in_app = detect_in_app(ua);
+    let client = Client {
+        kind: if name.is_empty() {
+            ClientKind::Unknown
+        } else {
+            ClientKind::Browser
+        },
+        ..Default::default()
+    };
+    let browser = Browser {
+        name,
+        version,
+        engine,
+        os: Some(os),
+        device,
+        device_class,
+        architecture,
+        runtime,
+        in_app,
+        client,
+    };
+    (browser, found)
+}
+
+// the token and the names, on the desktop and on the mobile.
+// the earlier token has the priority, as `Edg/` is with `Chrome/`.
+const BROWSER_TOKENS: &[(&str, &str, &str)] = &[
+    ("Edg/", "Edge", "Edge"),
+    ("EdgA/", "Edge Mobile", "Edge Mobile"),
+    ("EdgiOS/", "Edge Mobile", "Edge Mobile"),
+    ("OPR/", "Opera", "Opera Mobile"),
+    ("SamsungBrowser/", "Samsung Internet", "Samsung Internet"),
+    ("CriOS/", "Chrome Mobile iOS", "Chrome Mobile iOS"),
+    ("FxiOS/", "Firefox iOS", "Firefox iOS"),
+    ("HeadlessChrome/", "HeadlessChrome", "HeadlessChrome"),
+    ("Firefox/", "Firefox", "Firefox Mobile"),
+    ("Chrome/", "Chrome", "Chrome Mobile"),
+];
+
+// the products that the major browsers send, besides `BROWSER_TOKENS`
+const KNOWN_PRODUCTS: &[&str] = &[
+    "Mozilla",
+    "AppleWebKit",
+    "Gecko",
+    "Safari",
+    "Version",
+    "Mobile",
+];
+
+// true if all of the products outside the comments are known,
+// as `Mozilla/5.0`, `Chrome/142.0.0.0` and the bare `Mobile`.
+#[cfg_attr(not(feature = "extractor"), allow(dead_code))]
+fn has_known_products(ua: &str) -> bool {
+    all_products(ua, |name| {
+        KNOWN_PRODUCTS.contains(&name)
+            || BROWSER_TOKENS
+                .iter()
+                .any(|(token, _, _)| token.strip_suffix('/') == Some(name))
+    })
+}
+
+fn detect_browser(ua: &str, os_name: &str) -> Option<(String, Version)> {
+    let mobile = ua.contains("Mobile") && os_name != "iOS";
+    for (token, name, mobile_name) in BROWSER_TOKENS {
+        if let Some(version) = token_version(ua, token) {
+            let name = if *token == "Chrome/" && ua.contains("; wv)") {
+                "Chrome Mobile WebView"
+            } else if mobile {
+                mobile_name
+            } else {
+                name
+            };
+            return Some((name.to_string(), version));
+        }
+    }
+    if ua.contains("Safari/") {
+        if let Some(version) = token_version(ua, "Version/") {
+            let name = match os_name {
+                "iOS" => "Mobile Safari",
+                "Android" => "Android",
+                _ => "Safari",
+            };
+            return Some((name.to_string(), version));
+        }
+    }
+    None
+}
+
+fn detect_os(ua: &str) -> Os {
+    let os = |name: &str, version: Version| Os {
+        exact: is_exact(ua, name, &version),
+        name: name.to_string(),
+        version,
+    };
+    // `Android` before `Linux`, `iOS` before `Mac OS X`
+    if let Some(v) = token_version(ua, "Android ") {
+        return os("Android", v);
+    }
+    if ua.contains("Android") {
+        return os("Android", Version::default());
+    }
+    if ua.contains("iPhone") || ua.contains("iPad") || ua.contains("iPod") {
+        let v = underscore_version(ua, "iPhone OS ")
+            .or_else(|| underscore_version(ua, "CPU OS "))
+            .unwrap_or_default();
+        return os("iOS", v);
+    }
+    if let Some(v) = token_version(ua, "Windows NT ") {
+        let s = match (v.major, v.minor) {
+            (Some(10), _) => "10",
+            (Some(6), Some(3)) => "8.1",
+            (Some(6), Some(2)) => "8",
+            (Some(6), Some(1)) => "7",
+            (Some(6), Some(0)) => "Vista",
+            (Some(5), Some(1 | 2)) => "XP",
+            _ => "",
+        };
+        return os("Windows", Version::parse(s));
+    }
+    if ua.contains("Windows") {
+        return os("Windows", Version::default());
+    }
+    if let Some(v) = underscore_version(ua, "Mac OS X ") {
+        return os("Mac OS X", v);
+    }
+    if ua.contains("Macintosh") {
+        return os("Mac OS X", Version::default());
+    }
+    if ua.contains("CrOS") {
+        return os("Chrome OS", Version::default());
+    }
+    if ua.contains("Ubuntu") {
+        return os("Ubuntu", Version::default());
+    }
+    if ua.contains("Linux") || ua.contains("X11") {
+        return os("Linux", Version::default());
+    }
+    Os::default()
+}
+
+fn detect_device(ua: &str, os_name: &str) -> Device {
+    let apple = |name: &str| Device {
+        family: name.to_string(),
+        brand: "Apple".to_string(),
+        model: name.to_string(),
+        ..Default::default()
+    };
+    match os_name {
+        "iOS" => {
+            let name = ["iPad", "iPod", "iPhone"]
+                .into_iter()
+                .find(|a| ua.contains(a))
+                .unwrap_or_default();
+            apple(name)
+        }
+        "Mac OS X" => apple("Mac"),
+        "Android" => {
+            // `Android 14; SM-S928B)` or `Android 14; SM-S928B Build/UP1A`
+            let model = ua
+                .split_once("Android ")
+                .and_then(|(_, s)| s.split_once(';'))
+                .map(|(_, s)| s.split([')', ';']).next().unwrap_or_default())
+                .map(|s| s.split(" Build/").next().unwrap_or_default().trim())
+                .filter(|s| !s.is_empty() && *s != "Mobile" && !s.starts_with("rv:"))
+                .unwrap_or_default();
+            Device {
+                family: model.to_string(),
+                brand: String::new(),
+                model: model.to_string(),
+                ..Default::default()
+            }
+        }
+        _ => Device::default(),
+    }
+}
+
+// the version with the underscores, as `iPhone OS 18_3_1`
+fn underscore_version(ua: &str, token: &str) -> Option<Version> {
+    let idx = ua.find(token)? + token.len();
+    let s: String = ua[idx..]
+        .chars()
+        .take_while(|c| c.is_ascii_digit() || *c == '_' || *c == '.')
+        .map(|c| if c == '_' { '.' } else { c })
+        .collect();
+    if s.is_empty() {
+        return None;
+    }
+    Some(Version::parse(s.trim_end_matches('.')))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    fn lite(ua: &str) -> String {
+        let b = parse(ua).unwrap();
+        let os = b.os.unwrap();
+        format!(
+            "{} {} / {} {} / {}",
+            b.name, b.version, os.name, os.version, b.device
+        )
+    }
+    #[test]
+    fn test_desktop() {
+        assert_eq!(
+            lite("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/142.0.0.0 Safari/537.36 Edg/142.0.0.0"),
+            "Edge 142.0.0.0 / Windows 10 / "
+        );
+        assert_eq!(
+            lite(
+                "Mozilla/5.0 (Windows NT 10.0; Win64; x64; rv:145.0) Gecko/20100101 Firefox/145.0"
+            ),
+            "Firefox 145.0 / Windows 10 / "
+        );
+        assert_eq!(
+            lite("Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.5 Safari/605.1.15"),
+            "Safari 17.5 / Mac OS X 10.15.7 / Apple/Mac"
+        );
+        assert_eq!(
+            lite("Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/130.0.0.0 Safari/537.36 OPR/115.0.0.0"),
+            "Opera 115.0.0.0 / Linux  / "
+        );
+    }
+    #[test]
+    fn test_mobile() {
+        assert_eq!(
+            lite("Mozilla/5.0 (Linux; Android 14; SM-S928B) AppleWebKit/537.36 (KHTML, like Gecko) SamsungBrowser/25.0 Chrome/121.0.0.0 Mobile Safari/537.36"),
+            "Samsung Internet 25.0 / Android 14 / SM-S928B"
+        );
+        assert_eq!(
+            lite("Mozilla/5.0 (Linux; Android 15; Pixel 8 Build/AP4A.250105.002; wv) AppleWebKit/537.36 (KHTML, like Gecko) Version/4.0 Chrome/132.0.6834.163 Mobile Safari/537.36"),
+            "Chrome Mobile WebView 132.0.6834.163 / Android 15 / Pixel 8"
+        );
+        assert_eq!(
+            lite("Mozilla/5.0 (Android 15; Mobile; SM-G556B; rv:130.0) Gecko/130.0 Firefox/130.0"),
+            "Firefox Mobile 130.0 / Android 15 / "
+        );
+        assert_eq!(
+            lite("Mozilla/5.0 (iPhone; CPU iPhone OS 17_5 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.5 Mobile/15E148 Safari/604.1"),
+            "Mobile Safari 17.5 / iOS 17.5 / Apple/iPhone"
+        );
+        assert_eq!(
+            lite("Mozilla/5.0 (iPad; CPU OS 17_5 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) CriOS/126.0.6478.153 Mobile/15E148 Safari/604.1"),
+            "Chrome Mobile iOS 126.0.6478.153 / iOS 17.5 / Apple/iPad"
+        );
+    }
+    #[test]
+    fn test_not_found() {
+        assert!(parse("").is_none());
+        assert!(parse("curl/8.5.0").is_none());
+        let (b, found) = parse_partial("Mozilla/5.0 (Linux; Android 14; K) MyApp/1.0");
+        assert!(!found);
+        assert_eq!(b.os.unwrap().name, "Android");
+        assert_eq!(b.client.kind, ClientKind::Unknown);
+        // the unknown products with `Chrome/` are left to the full parser
+        for ua in [
+            "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 YaBrowser/24.1.0.0 Safari/537.36",
+            "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36 Vivaldi/6.5.3206.63",
+            "Mozilla/5.0 (Linux; U; Android 10; en-US; RMX2020 Build/QP1A.190711.020) AppleWebKit/537.36 (KHTML, like Gecko) Version/4.0 Chrome/78.0.3904.108 UCBrowser/13.4.0.1306 Mobile Safari/537.36",
+            "Mozilla/5.0 (Linux; Android 14; SM-S918B Build/UP1A.231005.007; wv) AppleWebKit/537.36 (KHTML, like Gecko) Version/4.0 Chrome/127.0.6533.103 Mobile Safari/537.36 Instagram 343.0.0.33.101 Android (34/14; 480dpi; 1080x2316; samsung; SM-S918B; dm3q; qcom; en_US; 628213426)",
+            "Mozilla/5.0 (Linux; Android 13; SM-G991B Build/TP1A.220624.014; wv) AppleWebKit/537.36 (KHTML, like Gecko) Version/4.0 Chrome/120.0.6099.144 Mobile Safari/537.36 Line/13.23.1/IAB",
+        ] {
+            assert!(parse(ua).is_none(), "{ua}");
+            assert!(parse_partial(ua).1, "{ua}");
+        }
+    }
+    #[cfg(feature = "extractor")]
+    #[test]
+    fn test_same_as_extractor() {
+        let uas = [
+            "Mozilla/5.0 (Linux; Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/142.0.0.0 Mobile Safari/537.36",
+            "Mozilla/5.0 (Linux; Android 14; Pixel 9 Build/AD1A.240411.003.A5; wv) AppleWebKit/537.36 (KHTML, like Gecko) Version/4.0 Chrome/124.0.6367.54 Mobile Safari/537.36",
+            "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.6099.230 Safari/537.36",
+            "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/142.0.0.0 Safari/537.36 Edg/142.0.0.0",
+            "Mozilla/5.0 (X11; Ubuntu; Linux x86_64; rv:145.0) Gecko/20100101 Firefox/145.0",
+            "Mozilla/5.0 (Android 15; Mobile; SM-G556B; rv:130.0) Gecko/130.0 Firefox/130.0",
+            "Mozilla/5.0 (iPhone; CPU iPhone OS 17_5 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.5 Mobile/15E148 Safari/604.1",
+        ];
+        let parser = super::super::try_init().unwrap();
+        for ua in uas {
+            let a = parse(ua).unwrap();
+            let b = parser.parse(ua);
+            assert_eq!((&a.name, &a.version), (&b.name, &b.version), "{ua}");
+            assert_eq!(a.os, b.os, "{ua}");
+            assert_eq!(a.engine, b.engine, "{ua}");
+            assert_eq!(a.device_class, b.device_class, "{ua}");
+        }
+    }
+}
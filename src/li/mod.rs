@@ -1,5 +1,47 @@
+mod architecture;
+pub use architecture::Architecture;
+mod batch;
+pub use batch::parse_many;
 mod broinfomaster;
 pub use broinfomaster::*;
+mod browserslist;
+pub use browserslist::Browserslist;
+#[cfg(feature = "extractor")]
+mod bundled;
+mod cache;
+pub use cache::CacheStats;
+mod client;
+pub use client::{Client, ClientKind};
+mod clienthints;
+pub use clienthints::*;
+mod deviceclass;
+mod devicemodel;
+pub use deviceclass::DeviceClass;
+mod engine;
+pub use engine::Engine;
+mod error;
+pub use error::*;
+#[cfg(feature = "extractor")]
+mod explain;
+#[cfg(feature = "extractor")]
+pub use explain::{explain, Explanation, RuleMatch, RuleSource, Stage};
+mod family;
+pub use family::{BrowserFamily, BrowserVariant, OsFamily};
+mod inapp;
+pub use inapp::InApp;
+#[cfg(any(feature = "lite", not(feature = "extractor")))]
+mod lite;
+mod osversion;
+mod release;
+pub use release::ReleaseInfo;
+mod requirement;
+pub use requirement::BrowserRequirement;
+mod runtime;
+pub use runtime::Runtime;
+mod uaparser;
+pub use uaparser::*;
+mod version;
+pub use version::*;
 
 /// Returns `javascript` to get the `user agent`.
 pub fn user_agent_js() -> &'static str {
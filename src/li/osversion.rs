@@ -0,0 +1,102 @@
+use super::engine::token_version;
+use super::{JsInfo, Os, Version};
+
+// The versions that `user agent` keeps at a fixed value,
+// so that the actual version may be newer.
+//
+// `Windows NT 10.0` is of `Windows 10` and `Windows 11`,
+// `Safari` and `Chrome` on `macOS 11` and later say `10_15_7`,
+// `Firefox` says `10.15`, and `Safari 26` on `iOS 26` says `18_6`.
+const FROZEN_VERSIONS: &[(&str, &str)] = &[
+    ("Windows", "10"),
+    ("Mac OS X", "10.15.7"),
+    ("Mac OS X", "10.15"),
+    ("iOS", "18.6"),
+];
+
+// the reduced `user agent` of `Chrome` on `Android`, as `Android 10; K`
+const REDUCED_ANDROID: &str = "Android 10; K)";
+
+/// Returns true if the version of `user agent` is the actual one.
+pub(crate) fn is_exact(ua: &str, os_name: &str, version: &Version) -> bool {
+    if version.is_empty() {
+        return false;
+    }
+    if os_name == "Android" && ua.contains(REDUCED_ANDROID) {
+        return false;
+    }
+    let s = version.to_string();
+    !FROZEN_VERSIONS
+        .iter()
+        .any(|(name, frozen)| *name == os_name && *frozen == s)
+}
+
+impl Os {
+    /// Refines the operating system with `user agent`
+    /// and the information obtained with `javascript`.
+    ///
+    /// An `iPad` says `iPad` in `navigator.platform`, or it pretends to be
+    /// a `Mac` with the touch points. The version of the latter is inferred
+    /// from the `Safari` version, as `Version/17.5` is of `iPadOS 17.5`.
+    ///
+    /// The exact version of `Windows 11` and of `macOS` is only in
+    /// `Sec-CH-UA-Platform-Version`, see [`ClientHints::refine()`](super::ClientHints::refine).
+    pub fn refine(mut self, user_agent: &str, jsinfo: &JsInfo) -> Self {
+        let touch = jsinfo.max_touch_points.is_some_and(|a| a > 1);
+        let desktop_ipad = self.name == "Mac OS X" && jsinfo.platform == "MacIntel" && touch;
+        if desktop_ipad {
+            self.version = token_version(user_agent, "Version/").unwrap_or_default();
+            self.exact = false;
+            self.name = IPADOS.to_string();
+        } else if self.name == "iOS" && jsinfo.platform == "iPad" {
+            self.name = IPADOS.to_string();
+        }
+        self
+    }
+}
+
+const IPADOS: &str = "iPadOS";
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    fn os(name: &str, version: &str, exact: bool) -> Os {
+        Os {
+            name: name.to_string(),
+            version: Version::parse(version),
+            exact,
+        }
+    }
+    #[test]
+    fn test_is_exact() {
+        let win = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/142.0.0.0 Safari/537.36";
+        assert!(!is_exact(win, "Windows", &Version::parse("10")));
+        assert!(is_exact(win, "Windows", &Version::parse("7")));
+        let mac = "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.5 Safari/605.1.15";
+        assert!(!is_exact(mac, "Mac OS X", &Version::parse("10.15.7")));
+        assert!(is_exact(mac, "Mac OS X", &Version::parse("10.14.6")));
+        let android = "Mozilla/5.0 (Linux; Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/142.0.0.0 Mobile Safari/537.36";
+        assert!(!is_exact(android, "Android", &Version::parse("10")));
+        let android = "Mozilla/5.0 (Linux; Android 10; SM-G973F) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Mobile Safari/537.36";
+        assert!(is_exact(android, "Android", &Version::parse("10")));
+        assert!(!is_exact("", "Linux", &Version::default()));
+    }
+    #[test]
+    fn test_refine_ipad() {
+        let ua = "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.5 Safari/605.1.15";
+        let mut jsinfo = JsInfo {
+            platform: "MacIntel".to_string(),
+            max_touch_points: Some(5),
+            ..Default::default()
+        };
+        let mac = os("Mac OS X", "10.15.7", false);
+        assert_eq!(mac.clone().refine(ua, &jsinfo), os("iPadOS", "17.5", false));
+        jsinfo.max_touch_points = Some(0);
+        assert_eq!(mac.clone().refine(ua, &jsinfo), mac);
+        //
+        let ua = "Mozilla/5.0 (iPad; CPU OS 17_5 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.5 Mobile/15E148 Safari/604.1";
+        jsinfo.platform = "iPad".to_string();
+        let ios = os("iOS", "17.5", true);
+        assert_eq!(ios.refine(ua, &jsinfo), os("iPadOS", "17.5", true));
+    }
+}
@@ -0,0 +1,138 @@
+use super::{Os, Version};
+use serde::Serialize;
+
+/// The release of the operating system, as `macOS Catalina`.
+///
+/// The offline table is converted from `resources/os_releases.yaml` by `build.rs`.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReleaseInfo {
+    /// a marketing name, as `macOS Catalina` or `Windows 11 23H2`
+    pub name: &'static str,
+    /// a codename, as `Tiramisu` of `Android 13`
+    pub codename: Option<&'static str>,
+    /// a date of the first public release, as `2019-10-07`
+    pub released: &'static str,
+    /// an API level of `Android`, as `33`
+    pub api_level: Option<u32>,
+}
+
+include!(concat!(env!("OUT_DIR"), "/os_releases.rs"));
+
+impl Os {
+    /// Returns the release of this, as `macOS Catalina` of `Mac OS X 10.15.7`.
+    ///
+    /// It covers `Windows` and its builds, `macOS`, `iOS`, `iPadOS`,
+    /// `Android` with the API levels, and `ChromeOS` of the platform version.
+    /// The frozen versions are mapped as they are, see [`Os::exact`].
+    /// The version newer than the table, as `Android 17`, is `None`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use browserinfo::{Os, Version};
+    ///
+    /// let os = Os {
+    ///     name: "Android".to_string(),
+    ///     version: Version::parse("13"),
+    ///     ..Default::default()
+    /// };
+    /// let info = os.release_info().unwrap();
+    /// assert_eq!(info.name, "Android 13");
+    /// assert_eq!(info.codename, Some("Tiramisu"));
+    /// assert_eq!(info.api_level, Some(33));
+    /// ```
+    pub fn release_info(&self) -> Option<ReleaseInfo> {
+        let family = self.family();
+        let family = family.id();
+        OS_RELEASES
+            .iter()
+            .filter(|(id, _, _)| *id == family)
+            .filter_map(|(_, version, info)| {
+                let prefix = Version::parse(version);
+                covers(&prefix, &self.version).then_some((prefix.parts().len(), info))
+            })
+            .max_by_key(|(len, _)| *len)
+            .map(|(_, info)| *info)
+    }
+}
+
+// true if `prefix` is the leading parts of `version`, as `10.15` of `10.15.7`.
+// the missing parts of `version` are `0`, as `8` is `8.0`.
+fn covers(prefix: &Version, version: &Version) -> bool {
+    let parts = prefix.parts();
+    if parts.is_empty() {
+        // as `XP` of `Windows`
+        return !prefix.suffix.is_empty() && prefix.suffix.eq_ignore_ascii_case(&version.suffix);
+    }
+    let actual = version.parts();
+    if actual.is_empty() {
+        return false;
+    }
+    parts
+        .iter()
+        .enumerate()
+        .all(|(i, a)| actual.get(i).copied().unwrap_or(0) == *a)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    fn info(name: &str, version: &str) -> Option<ReleaseInfo> {
+        let os = Os {
+            name: name.to_string(),
+            version: Version::parse(version),
+            ..Default::default()
+        };
+        os.release_info()
+    }
+    fn name(name: &str, version: &str) -> Option<&'static str> {
+        info(name, version).map(|a| a.name)
+    }
+    #[test]
+    fn test_release_info() {
+        assert_eq!(name("Mac OS X", "10.15.7"), Some("macOS Catalina"));
+        assert_eq!(name("Mac OS X", "14.5.0"), Some("macOS Sonoma"));
+        assert_eq!(name("Mac OS X", "10.1"), Some("Mac OS X Puma"));
+        assert_eq!(name("Windows", "10"), Some("Windows 10"));
+        assert_eq!(name("Windows", "11"), Some("Windows 11"));
+        assert_eq!(name("Windows", "10.0.22631"), Some("Windows 11 23H2"));
+        assert_eq!(name("Windows", "10.0.19045.5011"), Some("Windows 10 22H2"));
+        assert_eq!(name("Windows", "XP"), Some("Windows XP"));
+        assert_eq!(name("Windows", "8"), Some("Windows 8"));
+        assert_eq!(name("Windows", "8.1"), Some("Windows 8.1"));
+        assert_eq!(name("iOS", "17.5"), Some("iOS 17"));
+        assert_eq!(name("iPadOS", "18.1"), Some("iPadOS 18"));
+        assert_eq!(name("Chrome OS", "15662.72.0"), Some("ChromeOS 120"));
+        assert_eq!(name("Chrome OS", "16328.55.0"), Some("ChromeOS 139"));
+        // the frozen platform version of the reduced `user agent`
+        assert_eq!(name("Chrome OS", "14541.0.0"), None);
+        assert_eq!(name("Linux", ""), None);
+        assert_eq!(name("Android", ""), None);
+    }
+    #[test]
+    fn test_android() {
+        let a = info("Android", "8").unwrap();
+        assert_eq!(a.name, "Android 8.0 Oreo");
+        assert_eq!(a.api_level, Some(26));
+        let a = info("Android", "8.1.0").unwrap();
+        assert_eq!(a.api_level, Some(27));
+        let a = info("Android", "12.1").unwrap();
+        assert_eq!(a.name, "Android 12L");
+        assert_eq!(a.api_level, Some(32));
+        assert_eq!(info("Android", "12").unwrap().api_level, Some(31));
+        let a = info("Android", "14").unwrap();
+        assert_eq!(a.codename, Some("Upside Down Cake"));
+        assert_eq!(a.released, "2023-10-04");
+        let s = serde_json::to_string(&a).unwrap();
+        assert_eq!(
+            s,
+            r#"{"name":"Android 14","codename":"Upside Down Cake","released":"2023-10-04","api_level":34}"#
+        );
+    }
+    #[test]
+    fn test_past_the_end() {
+        // the newer one than the table is not taken as the last release
+        assert_eq!(name("Android", "17"), None);
+        assert_eq!(name("Chrome OS", "17000.0.0"), None);
+        assert_eq!(name("Mac OS X", "99.0"), None);
+    }
+}
@@ -0,0 +1,314 @@
+use super::{Browser, Error, Os, Result, Version};
+use std::cmp::Ordering;
+
+/// The parsed requirement of the browser or the operating system,
+/// as `Chrome >= 110 || Firefox >= 115 || Safari >= 16.4`.
+///
+/// The alternatives are separated by `||`, and each of them is
+/// the family name and an optional condition of the version.
+/// The operators are `>=`, `>`, `<=`, `<`, `=` and `!=`.
+/// The version is compared in its given parts,
+/// so `Chrome = 110` covers `110.0.5481.77`,
+/// and the missing parts are `0`, so `120` satisfies `Chrome >= 120.0`.
+///
+/// # Examples
+/// ```rust
+/// use browserinfo::{Browser, BrowserRequirement};
+///
+/// # fn func(browser: &Browser) -> anyhow::Result<()> {
+/// let req: BrowserRequirement = "Chrome >= 110 || Safari >= 16.4".parse()?;
+/// // "Chrome" covers "Chrome Mobile" and "Chrome Mobile WebView"
+/// let req = req.with_aliases(true);
+/// if req.matches(browser) {
+///     // ...
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct BrowserRequirement {
+    alternatives: Vec<Clause>,
+    aliases: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Clause {
+    family: String,
+    condition: Option<(Op, Version)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Ge,
+    Gt,
+    Le,
+    Lt,
+    Eq,
+    Ne,
+}
+
+impl Op {
+    const ALL: [(&'static str, Op); 7] = [
+        (">=", Op::Ge),
+        ("<=", Op::Le),
+        ("!=", Op::Ne),
+        ("==", Op::Eq),
+        (">", Op::Gt),
+        ("<", Op::Lt),
+        ("=", Op::Eq),
+    ];
+    fn as_str(&self) -> &'static str {
+        match self {
+            Op::Ge => ">=",
+            Op::Gt => ">",
+            Op::Le => "<=",
+            Op::Lt => "<",
+            Op::Eq => "=",
+            Op::Ne => "!=",
+        }
+    }
+    fn test(&self, ord: Ordering) -> bool {
+        match self {
+            Op::Ge => ord.is_ge(),
+            Op::Gt => ord.is_gt(),
+            Op::Le => ord.is_le(),
+            Op::Lt => ord.is_lt(),
+            Op::Eq => ord.is_eq(),
+            Op::Ne => ord.is_ne(),
+        }
+    }
+}
+
+// the family and its variants, that are covered with the aliases.
+// "Chrome Mobile iOS" and "Firefox iOS" are not, as they are of `WebKit`.
+const ALIASES: &[(&str, &[&str])] = &[
+    (
+        "Chrome",
+        &["Chrome Mobile", "Chrome Mobile WebView", "HeadlessChrome"],
+    ),
+    ("Edge", &["Edge Mobile"]),
+    ("Firefox", &["Firefox Mobile"]),
+    ("Opera", &["Opera Mobile"]),
+    ("Safari", &["Mobile Safari", "Mobile Safari UI/WKWebView"]),
+    ("iOS", &["iPadOS"]),
+];
+
+impl BrowserRequirement {
+    /// Parses the requirement.
+    pub fn parse(s: &str) -> Result<Self> {
+        let alternatives = s
+            .split("||")
+            .map(|a| Clause::parse(a).ok_or_else(|| invalid(s)))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            alternatives,
+            aliases: false,
+        })
+    }
+    /// Sets whether a family covers its variants,
+    /// as "Chrome" covers "Chrome Mobile" and "Chrome Mobile WebView",
+    /// and "iOS" covers "iPadOS".
+    ///
+    /// The default is false, that is the exact family name.
+    pub fn with_aliases(mut self, yes: bool) -> Self {
+        self.aliases = yes;
+        self
+    }
+    /// Returns true if the browser satisfies one of the alternatives.
+    pub fn matches(&self, browser: &Browser) -> bool {
+        self.matches_family(&browser.name, &browser.version)
+    }
+    /// Returns true if the operating system satisfies one of the alternatives.
+    pub fn matches_os(&self, os: &Os) -> bool {
+        self.matches_family(&os.name, &os.version)
+    }
+    fn matches_family(&self, name: &str, version: &Version) -> bool {
+        self.alternatives
+            .iter()
+            .any(|a| a.covers(name, self.aliases) && a.test(version))
+    }
+}
+
+impl Clause {
+    fn parse(s: &str) -> Option<Self> {
+        let s = s.trim();
+        let Some(idx) = s.find(['<', '>', '=', '!']) else {
+            return (!s.is_empty()).then(|| Clause {
+                family: s.to_string(),
+                condition: None,
+            });
+        };
+        let family = s[..idx].trim();
+        let rest = &s[idx..];
+        let (token, op) = Op::ALL.into_iter().find(|(a, _)| rest.starts_with(a))?;
+        let version = Version::parse(rest[token.len()..].trim());
+        if family.is_empty() || version.major.is_none() {
+            return None;
+        }
+        Some(Clause {
+            family: family.to_string(),
+            condition: Some((op, version)),
+        })
+    }
+    fn covers(&self, name: &str, aliases: bool) -> bool {
+        if self.family.eq_ignore_ascii_case(name) {
+            return true;
+        }
+        aliases
+            && ALIASES.iter().any(|(family, variants)| {
+                family.eq_ignore_ascii_case(&self.family)
+                    && variants.iter().any(|a| a.eq_ignore_ascii_case(name))
+            })
+    }
+    fn test(&self, version: &Version) -> bool {
+        let Some((op, required)) = &self.condition else {
+            return true;
+        };
+        if version.is_empty() {
+            return false;
+        }
+        op.test(truncate(version, required).cmp(required))
+    }
+}
+
+// the version in the parts of the required one, as `16.4` of `16.4.1`;
+// the shorter one, as `14` for `14.0`, is compared with the missing parts as `0`
+fn truncate(version: &Version, required: &Version) -> Version {
+    let mut truncated = version.truncate(required.parts().len());
+    if !required.suffix.is_empty() {
+        truncated.suffix = version.suffix.clone();
+    }
+    truncated
+}
+
+fn invalid(s: &str) -> Error {
+    Error::Validation(format!("requirement: {s:?}"))
+}
+
+impl std::str::FromStr for BrowserRequirement {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse(s)
+    }
+}
+
+impl std::fmt::Display for BrowserRequirement {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for (i, clause) in self.alternatives.iter().enumerate() {
+            if i > 0 {
+                write!(f, " || ")?;
+            }
+            write!(f, "{}", clause.family)?;
+            if let Some((op, version)) = &clause.condition {
+                write!(f, " {} {version}", op.as_str())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Browser {
+    /// Returns true if this satisfies the requirement,
+    /// as `Chrome >= 110 || Firefox >= 115 || Safari >= 16.4`.
+    ///
+    /// The family names are exact. See [`BrowserRequirement`] for the aliases.
+    pub fn satisfies(&self, requirement: &str) -> Result<bool> {
+        Ok(BrowserRequirement::parse(requirement)?.matches(self))
+    }
+}
+
+impl Os {
+    /// Returns true if this satisfies the requirement,
+    /// as `Android >= 10 || iOS >= 16`.
+    ///
+    /// The family names are exact. See [`BrowserRequirement`] for the aliases.
+    pub fn satisfies(&self, requirement: &str) -> Result<bool> {
+        Ok(BrowserRequirement::parse(requirement)?.matches_os(self))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    fn browser(name: &str, version: &str) -> Browser {
+        Browser {
+            name: name.to_string(),
+            version: Version::parse(version),
+            ..Default::default()
+        }
+    }
+    #[test]
+    fn test_satisfies() {
+        let req = "Chrome >= 110 || Firefox >= 115 || Safari >= 16.4";
+        assert!(browser("Chrome", "142.0.0.0").satisfies(req).unwrap());
+        assert!(!browser("Chrome", "109.0.5414.120").satisfies(req).unwrap());
+        assert!(browser("Safari", "16.4.1").satisfies(req).unwrap());
+        assert!(!browser("Safari", "16.3").satisfies(req).unwrap());
+        assert!(!browser("Chrome Mobile", "142.0.0.0")
+            .satisfies(req)
+            .unwrap());
+        assert!(!browser("Edge", "142.0.0.0").satisfies(req).unwrap());
+        assert!(!browser("Chrome", "").satisfies(req).unwrap());
+    }
+    #[test]
+    fn test_operators() {
+        let b = browser("Chrome", "110.0.5481.77");
+        assert!(b.satisfies("Chrome = 110").unwrap());
+        assert!(b.satisfies("Chrome <= 110").unwrap());
+        assert!(!b.satisfies("Chrome > 110").unwrap());
+        assert!(b.satisfies("Chrome > 109").unwrap());
+        assert!(b.satisfies("Chrome < 111").unwrap());
+        assert!(b.satisfies("Chrome != 109").unwrap());
+        assert!(b.satisfies("chrome").unwrap());
+        assert!(!b.satisfies("Chrome == 110.1").unwrap());
+        //
+        let b = browser("Chrome", "120");
+        assert!(b.satisfies("Chrome >= 120.0").unwrap());
+        assert!(b.satisfies("Chrome = 120.0.0").unwrap());
+        assert!(!b.satisfies("Chrome > 120.0").unwrap());
+        assert!(!b.satisfies("Chrome >= 120.0.1").unwrap());
+        assert!(b.satisfies("Chrome < 120.0.1").unwrap());
+    }
+    #[test]
+    fn test_aliases() {
+        let req = BrowserRequirement::parse("Chrome >= 110 || Safari >= 16.4")
+            .unwrap()
+            .with_aliases(true);
+        assert!(req.matches(&browser("Chrome Mobile", "142.0.0.0")));
+        assert!(req.matches(&browser("Chrome Mobile WebView", "124.0.6367.54")));
+        assert!(req.matches(&browser("Mobile Safari", "17.5")));
+        assert!(!req.matches(&browser("Chrome Mobile iOS", "126.0.6478.153")));
+        assert!(!req.matches(&browser("Chromium", "142.0.0.0")));
+    }
+    #[test]
+    fn test_os() {
+        let os = Os {
+            name: "Android".to_string(),
+            version: Version::parse("14"),
+            ..Default::default()
+        };
+        assert!(os.satisfies("Android >= 10 || iOS >= 16").unwrap());
+        assert!(!os.satisfies("Android >= 15").unwrap());
+        assert!(!os.satisfies("iOS").unwrap());
+        assert!(os.satisfies("Android >= 14.0").unwrap());
+        assert!(os.satisfies("Android = 14.0.0").unwrap());
+        //
+        let os = Os {
+            name: "iPadOS".to_string(),
+            version: Version::parse("17.5"),
+            ..Default::default()
+        };
+        assert!(!os.satisfies("iOS >= 16").unwrap());
+        let req = BrowserRequirement::parse("iOS >= 16").unwrap();
+        assert!(req.with_aliases(true).matches_os(&os));
+    }
+    #[test]
+    fn test_parse() {
+        let req: BrowserRequirement = "Chrome Mobile >=110|| Mac OS X < 10.15".parse().unwrap();
+        assert_eq!(req.to_string(), "Chrome Mobile >= 110 || Mac OS X < 10.15");
+        for s in ["", "Chrome >=", ">= 110", "Chrome => 110", "Chrome >= 1 ||"] {
+            let e = BrowserRequirement::parse(s).unwrap_err();
+            assert!(matches!(e, Error::Validation(_)), "{s}");
+        }
+    }
+}
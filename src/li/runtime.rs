@@ -0,0 +1,174 @@
+use super::engine::all_products;
+use super::OsFamily;
+use serde::{Deserialize, Serialize};
+
+/// The runtime that renders the page, a browser tab or an embedded web view.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum Runtime {
+    /// can not be classified, as a crawler
+    #[default]
+    Unknown,
+    /// a tab of a browser
+    BrowserTab,
+    /// `Android System WebView`, with the `wv` token
+    AndroidWebView,
+    /// `WKWebView` of `iOS` and `macOS`.
+    /// `SFSafariViewController` is the same as `Safari`, a browser tab.
+    WkWebView,
+    /// `Microsoft Edge WebView2` of `Windows`
+    WebView2,
+    /// `WebKitGTK` of `Linux`, as `wry` uses
+    WebKitGtk,
+    /// `Electron`
+    Electron,
+    /// `Tauri` or `wry`, that tells it in `user agent`
+    Tauri,
+    /// `Capacitor` or `Cordova`, that tells it in `user agent`
+    Capacitor,
+}
+
+impl Runtime {
+    /// Returns true if self is an embedded web view, not a browser tab.
+    pub fn is_embedded(&self) -> bool {
+        !matches!(self, Runtime::Unknown | Runtime::BrowserTab)
+    }
+}
+
+impl std::fmt::Display for Runtime {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let s = match self {
+            Runtime::Unknown => "unknown",
+            Runtime::BrowserTab => "browser_tab",
+            Runtime::AndroidWebView => "android_web_view",
+            Runtime::WkWebView => "wk_web_view",
+            Runtime::WebView2 => "web_view2",
+            Runtime::WebKitGtk => "web_kit_gtk",
+            Runtime::Electron => "electron",
+            Runtime::Tauri => "tauri",
+            Runtime::Capacitor => "capacitor",
+        };
+        write!(f, "{s}")
+    }
+}
+
+// the tokens that the frameworks add, the earlier has the priority.
+const FRAMEWORK_TOKENS: &[(&str, Runtime)] = &[
+    ("Electron/", Runtime::Electron),
+    ("Tauri", Runtime::Tauri),
+    ("wry/", Runtime::Tauri),
+    ("Capacitor", Runtime::Capacitor),
+    ("Cordova", Runtime::Capacitor),
+    ("WebView2", Runtime::WebView2),
+];
+
+// the products of the web view of `Android 4.4`, without the `wv` token,
+// that the browsers on it add their own one to, as `Quark/` or `UCBrowser/`.
+const KITKAT_WEBVIEW_PRODUCTS: &[&str] = &[
+    "Mozilla",
+    "AppleWebKit",
+    "Version",
+    "Chrome",
+    "Mobile",
+    "Safari",
+];
+
+/// Classifies the runtime from the tokens of `user agent` and the parsed result.
+///
+/// `WebView2` has the same `user agent` as `Edge`,
+/// that is found with the brand of the client hints.
+pub(crate) fn detect_runtime(ua: &str, name: &str, os_name: &str) -> Runtime {
+    if let Some((_, runtime)) = FRAMEWORK_TOKENS.iter().find(|(a, _)| ua.contains(a)) {
+        return *runtime;
+    }
+    if name.is_empty() || name == "Other" {
+        return Runtime::Unknown;
+    }
+    // the web views do not have the `Safari/` token
+    let webview = ua.contains("AppleWebKit/") && !ua.contains("Chrome/") && !ua.contains("Safari/");
+    match OsFamily::from_name(os_name) {
+        OsFamily::Android if ua.contains("; wv)") || is_kitkat_webview(ua) => {
+            Runtime::AndroidWebView
+        }
+        OsFamily::Ios | OsFamily::IpadOs | OsFamily::MacOs if webview => Runtime::WkWebView,
+        // `WebKitGTK` has the same `user agent` as `Epiphany` and the other browsers
+        OsFamily::Linux | OsFamily::Bsd if webview => Runtime::WebKitGtk,
+        _ => Runtime::BrowserTab,
+    }
+}
+
+// `Version/4.0` with `Chrome/` is of the older web views, without the `wv` token
+fn is_kitkat_webview(ua: &str) -> bool {
+    ua.contains("Version/4.0")
+        && ua.contains("Chrome/")
+        && all_products(ua, |name| KITKAT_WEBVIEW_PRODUCTS.contains(&name))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_detect() {
+        let ua = "Mozilla/5.0 (Linux; Android 14; Pixel 9 Build/AD1A.240411.003.A5; wv) AppleWebKit/537.36 (KHTML, like Gecko) Version/4.0 Chrome/124.0.6367.54 Mobile Safari/537.36";
+        let runtime = detect_runtime(ua, "Chrome Mobile WebView", "Android");
+        assert_eq!(runtime, Runtime::AndroidWebView);
+        assert!(runtime.is_embedded());
+        let ua = "Mozilla/5.0 (Linux; Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/142.0.0.0 Mobile Safari/537.36";
+        assert_eq!(
+            detect_runtime(ua, "Chrome Mobile", "Android"),
+            Runtime::BrowserTab
+        );
+        let ua = "Mozilla/5.0 (iPhone17,5; CPU iPhone OS 18_3_2 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Mobile/15E148";
+        assert_eq!(
+            detect_runtime(ua, "Mobile Safari UI/WKWebView", "iOS"),
+            Runtime::WkWebView
+        );
+        let ua = "Mozilla/5.0 (iPhone; CPU iPhone OS 17_5 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.5 Mobile/15E148 Safari/604.1";
+        assert_eq!(
+            detect_runtime(ua, "Mobile Safari", "iOS"),
+            Runtime::BrowserTab
+        );
+        let ua = "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/605.1.15 (KHTML, like Gecko)";
+        assert_eq!(
+            detect_runtime(ua, "Apple Mail", "Mac OS X"),
+            Runtime::WkWebView
+        );
+        let ua = "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.0 Safari/605.1.15";
+        assert_eq!(detect_runtime(ua, "Safari", "Linux"), Runtime::BrowserTab);
+        let ua = "Mozilla/5.0 (X11; Ubuntu; Linux x86_64) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.0 Safari/605.1.15 Epiphany/605.1.15";
+        assert_eq!(
+            detect_runtime(ua, "Epiphany", "Ubuntu"),
+            Runtime::BrowserTab
+        );
+        let ua = "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/605.1.15 (KHTML, like Gecko)";
+        assert_eq!(
+            detect_runtime(ua, "Apple Mail", "Linux"),
+            Runtime::WebKitGtk
+        );
+        let ua = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) my-app/1.0.0 Chrome/130.0.6723.191 Electron/33.3.1 Safari/537.36";
+        assert_eq!(detect_runtime(ua, "Electron", "Windows"), Runtime::Electron);
+        let ua = "Mozilla/5.0 (Linux; Android 14; Pixel 8; wv) AppleWebKit/537.36 (KHTML, like Gecko) Version/4.0 Chrome/124.0.6367.54 Mobile Safari/537.36 Capacitor";
+        assert_eq!(
+            detect_runtime(ua, "Chrome Mobile WebView", "Android"),
+            Runtime::Capacitor
+        );
+        assert_eq!(detect_runtime("", "", ""), Runtime::Unknown);
+        assert_eq!(Runtime::WebView2.to_string(), "web_view2");
+    }
+    #[test]
+    fn test_kitkat_webview() {
+        let ua = "Mozilla/5.0 (Linux; Android 4.4.2; Nexus 5 Build/KOT49H) AppleWebKit/537.36 (KHTML, like Gecko) Version/4.0 Chrome/30.0.0.0 Mobile Safari/537.36";
+        assert_eq!(
+            detect_runtime(ua, "Chrome Mobile WebView", "Android"),
+            Runtime::AndroidWebView
+        );
+        // the browsers of the web view, with their own products
+        for (ua, name) in [
+            ("Mozilla/5.0 (Linux; U; Android 12; zh-CN; V2140A Build/SP1A.210812.003) AppleWebKit/537.36 (KHTML, like Gecko) Version/4.0 Chrome/100.0.4896.58 Quark/6.2.0.321 Mobile Safari/537.36", "Quark"),
+            ("Mozilla/5.0 (Linux; U; Android 13; zh-cn; 22081212C Build/TKQ1.220829.002) AppleWebKit/537.36 (KHTML, like Gecko) Version/4.0 Chrome/109.0.5414.118 Mobile Safari/537.36 XiaoMi/MiuiBrowser/17.7.120126 swan-mibrowser", "MiuiBrowser"),
+            ("Mozilla/5.0 (Linux; U; Android 10; en-US; SM-A105F Build/QP1A.190711.020) AppleWebKit/537.36 (KHTML, like Gecko) Version/4.0 Chrome/100.0.4896.58 UCBrowser/13.4.0.1306 Mobile Safari/537.36", "UC Browser"),
+        ] {
+            assert_eq!(detect_runtime(ua, name, "Android"), Runtime::BrowserTab, "{name}");
+        }
+    }
+}
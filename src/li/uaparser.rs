@@ -0,0 +1,601 @@
+use super::cache::ParseCache;
+#[cfg(feature = "extractor")]
+use super::client::{ClientParser, ClientRules};
+#[cfg(feature = "yaml")]
+use super::devicemodel::DeviceModelFile;
+use super::devicemodel::{DeviceModel, DeviceModels};
+#[cfg(feature = "extractor")]
+use super::explain::RuleSources;
+#[cfg(any(feature = "lite", not(feature = "extractor")))]
+use super::lite;
+#[cfg(feature = "extractor")]
+use super::{bundled, convert_extracted, Extracted};
+use super::{Browser, CacheStats};
+#[cfg(feature = "yaml")]
+use serde::Deserialize;
+#[cfg(feature = "yaml")]
+use std::io::Read;
+#[cfg(feature = "yaml")]
+use std::path::PathBuf;
+
+/// The `user agent` parser, that has the compiled regexes.
+///
+/// The default is compiled from the bundled `regexes.yaml` and `bots.yaml`.
+/// Use [`UaParser::builder()`] to load a newer `regexes.yaml`,
+/// or to add your own rules ahead of it.
+///
+/// Without the `extractor` feature, this is the lightweight parser
+/// that finds the tokens of the major browsers.
+pub struct UaParser {
+    #[cfg(feature = "extractor")]
+    pub(crate) extractor: ua_parser::Extractor<'static>,
+    #[cfg(feature = "extractor")]
+    pub(crate) clients: ClientRules,
+    #[cfg(feature = "extractor")]
+    pub(crate) sources: RuleSources,
+    #[cfg(all(feature = "lite", feature = "extractor"))]
+    lite: bool,
+    pub(crate) models: DeviceModels,
+    cache: Option<ParseCache>,
+}
+
+impl UaParser {
+    /// Creates a parser from the bundled `regexes.yaml`.
+    pub fn new() -> Result<Self> {
+        Self::builder().build()
+    }
+    /// Creates a builder of the parser.
+    pub fn builder() -> UaParserBuilder {
+        UaParserBuilder::default()
+    }
+    /// Parses `user agent` and generates `Browser`.
+    ///
+    /// If the parser has the cache, the cached result is returned.
+    pub fn parse(&self, ua: &str) -> Browser {
+        match &self.cache {
+            Some(cache) => cache.get_or_insert_with(ua, || self.parse_uncached(ua)),
+            None => self.parse_uncached(ua),
+        }
+    }
+    #[cfg(feature = "extractor")]
+    fn parse_uncached(&self, ua: &str) -> Browser {
+        match self.parse_lite(ua) {
+            Some(mut browser) => {
+                self.clients.classify(ua, &mut browser);
+                self.models.name(&mut browser.device);
+                browser
+            }
+            None => self.convert_extracted(ua, self.extractor.extract(ua)),
+        }
+    }
+    // `Browser` of the result of the extractor, without the lite parser and the cache
+    #[cfg(feature = "extractor")]
+    pub(crate) fn convert_extracted(&self, ua: &str, extracted: Extracted) -> Browser {
+        let mut browser = convert_extracted(ua, extracted);
+        self.clients.classify(ua, &mut browser);
+        self.models.name(&mut browser.device);
+        browser
+    }
+    #[cfg(all(feature = "lite", feature = "extractor"))]
+    fn parse_lite(&self, ua: &str) -> Option<Browser> {
+        if self.lite {
+            lite::parse(ua)
+        } else {
+            None
+        }
+    }
+    #[cfg(all(not(feature = "lite"), feature = "extractor"))]
+    fn parse_lite(&self, _ua: &str) -> Option<Browser> {
+        None
+    }
+    #[cfg(not(feature = "extractor"))]
+    fn parse_uncached(&self, ua: &str) -> Browser {
+        let mut browser = lite::parse_partial(ua).0;
+        self.models.name(&mut browser.device);
+        browser
+    }
+    /// Returns the statistics of the cache, or `None` without the cache.
+    pub fn cache_stats(&self) -> Option<CacheStats> {
+        self.cache.as_ref().map(|a| a.stats())
+    }
+    /// Clears the entries and the statistics of the cache.
+    pub fn clear_cache(&self) {
+        if let Some(cache) = &self.cache {
+            cache.clear();
+        }
+    }
+}
+
+/// The error of loading or compiling the regexes.
+#[derive(Debug)]
+pub enum RegexesError {
+    /// Reading the file or the reader failed.
+    Io(std::io::Error),
+    /// The contents are not in the `regexes.yaml` format.
+    #[cfg(feature = "yaml")]
+    Yaml(serde_yaml::Error),
+    /// One of the regexes could not be compiled.
+    #[cfg(feature = "extractor")]
+    Compile(ua_parser::Error),
+    /// One of the regexes of `bots.yaml` could not be compiled.
+    #[cfg(feature = "extractor")]
+    Bots(regex::Error),
+}
+
+impl std::fmt::Display for RegexesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            RegexesError::Io(_) => write!(f, "can not read the regexes"),
+            #[cfg(feature = "yaml")]
+            RegexesError::Yaml(_) => write!(f, "invalid regexes yaml"),
+            #[cfg(feature = "extractor")]
+            RegexesError::Compile(_) => write!(f, "can not compile the regexes"),
+            #[cfg(feature = "extractor")]
+            RegexesError::Bots(_) => write!(f, "can not compile the bots regexes"),
+        }
+    }
+}
+
+impl std::error::Error for RegexesError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RegexesError::Io(e) => Some(e),
+            #[cfg(feature = "yaml")]
+            RegexesError::Yaml(e) => Some(e),
+            #[cfg(feature = "extractor")]
+            RegexesError::Compile(e) => Some(e),
+            #[cfg(feature = "extractor")]
+            RegexesError::Bots(e) => Some(e),
+        }
+    }
+}
+
+impl From<std::io::Error> for RegexesError {
+    fn from(e: std::io::Error) -> Self {
+        RegexesError::Io(e)
+    }
+}
+
+#[cfg(feature = "yaml")]
+impl From<serde_yaml::Error> for RegexesError {
+    fn from(e: serde_yaml::Error) -> Self {
+        RegexesError::Yaml(e)
+    }
+}
+
+#[cfg(feature = "extractor")]
+impl From<ua_parser::Error> for RegexesError {
+    fn from(e: ua_parser::Error) -> Self {
+        RegexesError::Compile(e)
+    }
+}
+
+#[cfg(feature = "extractor")]
+impl From<regex::Error> for RegexesError {
+    fn from(e: regex::Error) -> Self {
+        RegexesError::Bots(e)
+    }
+}
+
+type Result<T> = std::result::Result<T, RegexesError>;
+
+/// A source of the `regexes.yaml` format.
+#[cfg(feature = "yaml")]
+enum Source {
+    Path(PathBuf),
+    Str(String),
+    Reader(Box<dyn Read>),
+}
+
+#[cfg(feature = "yaml")]
+impl Source {
+    fn read_to_string(self) -> Result<String> {
+        let s = match self {
+            Source::Path(path) => std::fs::read_to_string(path)?,
+            Source::Str(s) => s,
+            Source::Reader(mut rdr) => {
+                let mut s = String::new();
+                rdr.read_to_string(&mut s)?;
+                s
+            }
+        };
+        Ok(s)
+    }
+}
+
+/// The overlay rules. Each of the lists may be omitted.
+///
+/// `client_parsers` is in the `bots.yaml` format.
+#[cfg(feature = "extractor")]
+#[derive(Default)]
+#[cfg_attr(feature = "yaml", derive(Deserialize))]
+#[cfg_attr(feature = "yaml", serde(default))]
+struct Overlay {
+    user_agent_parsers: Vec<ua_parser::user_agent::Parser<'static>>,
+    os_parsers: Vec<ua_parser::os::Parser<'static>>,
+    device_parsers: Vec<ua_parser::device::Parser<'static>>,
+    client_parsers: Vec<ClientParser>,
+}
+
+/// The builder of [`UaParser`].
+///
+/// Loading the rules from files needs the `yaml` feature.
+///
+/// # Examples
+/// ```rust
+/// use browserinfo::UaParser;
+///
+/// # fn func() -> anyhow::Result<()> {
+/// # #[cfg(feature = "yaml")]
+/// # {
+/// let parser = UaParser::builder()
+///     .regexes_path("/usr/share/uap-core/regexes.yaml")
+///     .overlay_str(concat!(
+///         "user_agent_parsers:\n",
+///         "  - regex: '(MyApp)/(\\d+)\\.(\\d+)'\n",
+///     ))
+///     .build()?;
+/// let browser = parser.parse("MyApp/1.2");
+/// # }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default)]
+pub struct UaParserBuilder {
+    #[cfg(feature = "yaml")]
+    regexes: Option<Source>,
+    #[cfg(feature = "yaml")]
+    bots: Option<Source>,
+    #[cfg(feature = "yaml")]
+    overlays: Vec<Source>,
+    #[cfg(feature = "yaml")]
+    device_models: Vec<Source>,
+    #[cfg(all(feature = "lite", feature = "extractor"))]
+    lite: bool,
+    cache_size: usize,
+}
+
+impl UaParserBuilder {
+    /// Sets the maximum number of the cached results.
+    ///
+    /// The results are cached on the exact `user agent`,
+    /// and at most `size` results are kept.
+    /// The default is 0, that is no cache.
+    pub fn cache_size(mut self, size: usize) -> Self {
+        self.cache_size = size;
+        self
+    }
+    /// Tries the lightweight parser first,
+    /// and uses the full parser only if the browser is not found.
+    ///
+    /// This is faster for the major browsers, but the results may differ
+    /// in the details, as the device brand.
+    /// A `user agent` with an unknown product token, as `YaBrowser/`,
+    /// is always parsed with the full parser.
+    #[cfg(all(feature = "lite", feature = "extractor"))]
+    pub fn lite(mut self, yes: bool) -> Self {
+        self.lite = yes;
+        self
+    }
+    /// Loads all sources and compiles the regexes.
+    ///
+    /// Without the sources, the bundled rules are used,
+    /// that are converted into the tables at build time.
+    #[cfg(feature = "extractor")]
+    pub fn build(mut self) -> Result<UaParser> {
+        let (overlay, loaded, bots) = self.load_sources()?;
+        let models = DeviceModels::new(self.load_device_models()?);
+        let mut regexes = ua_parser::Regexes {
+            user_agent_parsers: overlay.user_agent_parsers,
+            os_parsers: overlay.os_parsers,
+            device_parsers: overlay.device_parsers,
+        };
+        // the overlay and the loaded `regexes.yaml`, for `explain()`
+        let sources = RuleSources::new(&regexes, loaded.as_ref());
+        let base = loaded.unwrap_or_else(bundled::regexes);
+        regexes.user_agent_parsers.extend(base.user_agent_parsers);
+        regexes.os_parsers.extend(base.os_parsers);
+        regexes.device_parsers.extend(base.device_parsers);
+        let mut client_parsers = overlay.client_parsers;
+        client_parsers.extend(bots.unwrap_or_else(bundled::client_parsers));
+        let clients = ClientRules::new(client_parsers)?;
+        let extractor = ua_parser::Extractor::try_from(regexes)?;
+        let cache = (self.cache_size > 0).then(|| ParseCache::new(self.cache_size));
+        Ok(UaParser {
+            extractor,
+            clients,
+            sources,
+            #[cfg(feature = "lite")]
+            lite: self.lite,
+            models,
+            cache,
+        })
+    }
+    /// Creates the lightweight parser.
+    #[cfg(not(feature = "extractor"))]
+    pub fn build(mut self) -> Result<UaParser> {
+        let models = DeviceModels::new(self.load_device_models()?);
+        let cache = (self.cache_size > 0).then(|| ParseCache::new(self.cache_size));
+        Ok(UaParser { models, cache })
+    }
+    #[cfg(all(feature = "extractor", not(feature = "yaml")))]
+    fn load_sources(&mut self) -> Result<Sources> {
+        Ok((Overlay::default(), None, None))
+    }
+    #[cfg(not(feature = "yaml"))]
+    fn load_device_models(&mut self) -> Result<Vec<DeviceModel>> {
+        Ok(Vec::new())
+    }
+}
+
+// the overlay, `regexes.yaml` and `bots.yaml`
+#[cfg(feature = "extractor")]
+type Sources = (
+    Overlay,
+    Option<ua_parser::Regexes<'static>>,
+    Option<Vec<ClientParser>>,
+);
+
+#[cfg(feature = "yaml")]
+impl UaParserBuilder {
+    /// Loads `regexes.yaml` from the path instead of the bundled one.
+    pub fn regexes_path<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.regexes = Some(Source::Path(path.into()));
+        self
+    }
+    /// Loads `regexes.yaml` from the string instead of the bundled one.
+    pub fn regexes_str<S: Into<String>>(mut self, s: S) -> Self {
+        self.regexes = Some(Source::Str(s.into()));
+        self
+    }
+    /// Loads `regexes.yaml` from the reader instead of the bundled one.
+    pub fn regexes_reader<R: Read + 'static>(mut self, rdr: R) -> Self {
+        self.regexes = Some(Source::Reader(Box::new(rdr)));
+        self
+    }
+    /// Loads `bots.yaml` from the path instead of the bundled one.
+    ///
+    /// `bots.yaml` has the rules of the non-browser clients, as crawlers.
+    pub fn bots_path<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.bots = Some(Source::Path(path.into()));
+        self
+    }
+    /// Loads `bots.yaml` from the string instead of the bundled one.
+    pub fn bots_str<S: Into<String>>(mut self, s: S) -> Self {
+        self.bots = Some(Source::Str(s.into()));
+        self
+    }
+    /// Loads `bots.yaml` from the reader instead of the bundled one.
+    pub fn bots_reader<R: Read + 'static>(mut self, rdr: R) -> Self {
+        self.bots = Some(Source::Reader(Box::new(rdr)));
+        self
+    }
+    /// Adds overlay rules from the path.
+    ///
+    /// The overlay rules are in the `regexes.yaml` format,
+    /// and are tried ahead of the `regexes.yaml`.
+    /// `client_parsers` in the overlay are tried ahead of the `bots.yaml`.
+    /// The later added overlay has the lower priority.
+    pub fn overlay_path<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.overlays.push(Source::Path(path.into()));
+        self
+    }
+    /// Adds overlay rules from the string.
+    pub fn overlay_str<S: Into<String>>(mut self, s: S) -> Self {
+        self.overlays.push(Source::Str(s.into()));
+        self
+    }
+    /// Adds overlay rules from the reader.
+    pub fn overlay_reader<R: Read + 'static>(mut self, rdr: R) -> Self {
+        self.overlays.push(Source::Reader(Box::new(rdr)));
+        self
+    }
+    /// Adds the marketing names of the device models from the path.
+    ///
+    /// The file is in the format of the bundled `device_models.yaml`,
+    /// and is tried ahead of it. The later added file has the lower priority.
+    pub fn device_models_path<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.device_models.push(Source::Path(path.into()));
+        self
+    }
+    /// Adds the marketing names of the device models from the string.
+    pub fn device_models_str<S: Into<String>>(mut self, s: S) -> Self {
+        self.device_models.push(Source::Str(s.into()));
+        self
+    }
+    /// Adds the marketing names of the device models from the reader.
+    pub fn device_models_reader<R: Read + 'static>(mut self, rdr: R) -> Self {
+        self.device_models.push(Source::Reader(Box::new(rdr)));
+        self
+    }
+    fn load_device_models(&mut self) -> Result<Vec<DeviceModel>> {
+        let mut models = Vec::new();
+        for src in std::mem::take(&mut self.device_models) {
+            let file: DeviceModelFile = serde_yaml::from_str(&src.read_to_string()?)?;
+            models.extend(file.device_models);
+        }
+        Ok(models)
+    }
+    fn load_sources(&mut self) -> Result<Sources> {
+        let mut overlay = Overlay::default();
+        for src in std::mem::take(&mut self.overlays) {
+            let ov: Overlay = serde_yaml::from_str(&src.read_to_string()?)?;
+            overlay.user_agent_parsers.extend(ov.user_agent_parsers);
+            overlay.os_parsers.extend(ov.os_parsers);
+            overlay.device_parsers.extend(ov.device_parsers);
+            overlay.client_parsers.extend(ov.client_parsers);
+        }
+        let regexes = match self.regexes.take() {
+            Some(src) => Some(serde_yaml::from_str(&src.read_to_string()?)?),
+            None => None,
+        };
+        let bots = match self.bots.take() {
+            Some(src) => {
+                let bots: Overlay = serde_yaml::from_str(&src.read_to_string()?)?;
+                Some(bots.client_parsers)
+            }
+            None => None,
+        };
+        Ok((overlay, regexes, bots))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_bundled() {
+        let parser = UaParser::new().unwrap();
+        let browser = parser.parse(
+            r#"Mozilla/5.0 (X11; Ubuntu; Linux x86_64; rv:145.0) Gecko/20100101 Firefox/145.0"#,
+        );
+        assert_eq!(browser.name, "Firefox");
+        assert_eq!(browser.version.to_string(), "145.0");
+    }
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn test_regexes_str() {
+        let s = concat!(
+            "user_agent_parsers:\n",
+            "  - regex: '(Firefox)/(\\d+)\\.(\\d+)'\n",
+            "    family_replacement: 'Fx'\n",
+            "os_parsers: []\n",
+            "device_parsers: []\n",
+        );
+        let parser = UaParser::builder().regexes_str(s).build().unwrap();
+        let browser = parser.parse(
+            r#"Mozilla/5.0 (X11; Ubuntu; Linux x86_64; rv:145.0) Gecko/20100101 Firefox/145.0"#,
+        );
+        assert_eq!(browser.name, "Fx");
+        assert_eq!(browser.version.to_string(), "145.0");
+        assert_eq!(browser.os.unwrap().name, "");
+    }
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn test_regexes_reader() {
+        let s = concat!(
+            "user_agent_parsers:\n",
+            "  - regex: '(Firefox)/(\\d+)\\.(\\d+)'\n",
+            "os_parsers: []\n",
+            "device_parsers: []\n",
+        );
+        let parser = UaParser::builder()
+            .regexes_reader(std::io::Cursor::new(s.as_bytes().to_vec()))
+            .build()
+            .unwrap();
+        let browser = parser.parse(r#"Gecko/20100101 Firefox/145.0"#);
+        assert_eq!(browser.name, "Firefox");
+    }
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn test_overlay() {
+        let ua = r#"Mozilla/5.0 (Linux; Android 11; S5-SH Build/S2014; wv) AppleWebKit/537.36 (KHTML, like Gecko) Version/4.0 Chrome/142.0.7444.102 Mobile Safari/537.36 MyApp/3.1"#;
+        let s = concat!(
+            "user_agent_parsers:\n",
+            "  - regex: '(MyApp)/(\\d+)\\.(\\d+)'\n",
+            "device_parsers:\n",
+            "  - regex: '; (S5-SH) Build/'\n",
+            "    brand_replacement: 'Sharp'\n",
+            "    model_replacement: 'Simple Smartphone 5'\n",
+        );
+        let parser = UaParser::builder().overlay_str(s).build().unwrap();
+        let browser = parser.parse(ua);
+        assert_eq!(browser.name, "MyApp");
+        assert_eq!(browser.version.to_string(), "3.1");
+        // the bundled os rules are still used
+        assert_eq!(browser.os.unwrap().name, "Android");
+        assert_eq!(browser.device.to_string(), "Sharp/Simple Smartphone 5");
+    }
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn test_bots() {
+        let s = concat!(
+            "client_parsers:\n",
+            "  - regex: 'MyCrawler/'\n",
+            "    kind: search_crawler\n",
+            "    name: 'MyCrawler'\n",
+            "    vendor: 'Me'\n",
+        );
+        let parser = UaParser::builder().bots_str(s).build().unwrap();
+        let browser = parser.parse("MyCrawler/1.0");
+        assert_eq!(browser.client.kind, super::super::ClientKind::SearchCrawler);
+        assert_eq!(browser.client.vendor, "Me");
+        // the bundled bots.yaml is replaced
+        let browser = parser.parse("curl/8.5.0");
+        assert_ne!(browser.client.kind, super::super::ClientKind::HttpLibrary);
+        // the overlay is tried ahead of the bundled one
+        let parser = UaParser::builder().overlay_str(s).build().unwrap();
+        assert_eq!(parser.parse("MyCrawler/1.0").client.name, "MyCrawler");
+        assert_eq!(
+            parser.parse("curl/8.5.0").client.kind,
+            super::super::ClientKind::HttpLibrary
+        );
+        let r = UaParser::builder()
+            .bots_str("client_parsers:\n  - regex: '(abc'\n    kind: monitoring\n    name: a\n")
+            .build();
+        assert!(matches!(r, Err(RegexesError::Bots(_))));
+    }
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn test_device_models() {
+        let ua = r#"Mozilla/5.0 (Linux; Android 11; S5-SH Build/S2014; wv) AppleWebKit/537.36 (KHTML, like Gecko) Version/4.0 Chrome/142.0.7444.102 Mobile Safari/537.36"#;
+        let s = concat!(
+            "device_models:\n",
+            "  - model: 'S5-SH'\n",
+            "    manufacturer: 'Sharp'\n",
+            "    name: 'Simple Smartphone 5'\n",
+        );
+        let parser = UaParser::builder().device_models_str(s).build().unwrap();
+        let device = parser.parse(ua).device;
+        assert_eq!(device.manufacturer, "Sharp");
+        assert_eq!(device.marketing_name, "Simple Smartphone 5");
+        // the bundled ones are still used
+        let ua = r#"Mozilla/5.0 (Linux; Android 14; SM-S918B) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/142.0.0.0 Mobile Safari/537.36"#;
+        assert_eq!(parser.parse(ua).device.marketing_name, "Galaxy S23 Ultra");
+        let r = UaParser::builder()
+            .device_models_str("device_models:\n  - model: 'S5-SH'\n")
+            .build();
+        assert!(matches!(r, Err(RegexesError::Yaml(_))));
+    }
+    #[test]
+    fn test_cache() {
+        let ua =
+            r#"Mozilla/5.0 (X11; Ubuntu; Linux x86_64; rv:145.0) Gecko/20100101 Firefox/145.0"#;
+        let parser = UaParser::builder().cache_size(16).build().unwrap();
+        let browser1 = parser.parse(ua);
+        let browser2 = parser.parse(ua);
+        assert_eq!(browser1, browser2);
+        let stats = parser.cache_stats().unwrap();
+        assert_eq!((stats.hits, stats.misses, stats.len), (1, 1, 1));
+        parser.clear_cache();
+        assert_eq!(parser.cache_stats().unwrap().len, 0);
+        //
+        let parser = super::super::try_init().unwrap();
+        assert!(parser.cache_stats().is_none());
+    }
+    #[cfg(all(feature = "lite", feature = "extractor"))]
+    #[test]
+    fn test_lite() {
+        let parser = UaParser::builder().lite(true).build().unwrap();
+        let ua = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 YaBrowser/24.1.0.0 Safari/537.36";
+        assert_eq!(parser.parse(ua).name, "Yandex Browser");
+        let ua = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/142.0.0.0 Safari/537.36";
+        assert_eq!(parser.parse(ua).name, "Chrome");
+    }
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn test_error() {
+        let r = UaParser::builder()
+            .regexes_str("user_agent_parsers: [")
+            .build();
+        assert!(r.is_err());
+        let r = UaParser::builder()
+            .regexes_path("/nonexistent/regexes.yaml")
+            .build();
+        assert!(r.is_err());
+        let r = UaParser::builder()
+            .overlay_str("user_agent_parsers:\n  - regex: '(abc'\n")
+            .build();
+        assert!(r.is_err());
+    }
+}
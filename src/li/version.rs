@@ -0,0 +1,306 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::cmp::Ordering;
+
+/// A dotted version such as `142.0.7444.102`.
+///
+/// The numeric parts are kept separately and the non-numeric
+/// remainder (e.g. `b3` in `3.0b3`) is kept verbatim as `suffix`.
+/// It is compared numerically, with the missing parts as `0`,
+/// so that `120` equals `120.0`, and is (de)serialized as the dotted string.
+#[derive(Default, Clone)]
+pub struct Version {
+    /// a major version
+    pub major: Option<u32>,
+    /// a minor version
+    pub minor: Option<u32>,
+    /// a patch version
+    pub patch: Option<u32>,
+    /// a patch minor version
+    pub patch_minor: Option<u32>,
+    /// a non-numeric remainder
+    pub suffix: String,
+}
+
+impl Version {
+    /// Parses a dotted version string.
+    ///
+    /// This never fails: the first part that is not a plain number
+    /// and everything after it goes into `suffix`.
+    /// The numeric parts after the fourth one, as `.106` of `468.0.0.50.106`,
+    /// are kept in `suffix`, and compared as the numbers.
+    pub fn parse(s: &str) -> Self {
+        let mut nums: [Option<u32>; 4] = [None; 4];
+        let mut rest = s;
+        for (i, num) in nums.iter_mut().enumerate() {
+            let body = if i == 0 {
+                rest
+            } else if let Some(body) = rest.strip_prefix('.') {
+                body
+            } else {
+                break;
+            };
+            let len = body.bytes().take_while(|b| b.is_ascii_digit()).count();
+            let Some(n) = body[..len].parse::<u32>().ok() else {
+                break;
+            };
+            *num = Some(n);
+            rest = &body[len..];
+        }
+        let [major, minor, patch, patch_minor] = nums;
+        Self {
+            major,
+            minor,
+            patch,
+            patch_minor,
+            suffix: rest.to_string(),
+        }
+    }
+    /// Creates a version from the four optional parts of `ua_parser`.
+    #[cfg_attr(not(feature = "extractor"), allow(dead_code))]
+    pub(crate) fn from_parts(parts: [Option<&str>; 4]) -> Self {
+        let mut s = String::new();
+        for part in parts.into_iter().map_while(|a| a) {
+            if !s.is_empty() {
+                s.push('.');
+            }
+            s.push_str(part);
+        }
+        Self::parse(&s)
+    }
+    /// Returns true if self has neither a number nor a suffix.
+    pub fn is_empty(&self) -> bool {
+        self.major.is_none() && self.suffix.is_empty()
+    }
+    /// Returns the numeric parts that are present.
+    pub fn parts(&self) -> Vec<u32> {
+        [self.major, self.minor, self.patch, self.patch_minor]
+            .into_iter()
+            .map_while(|a| a)
+            .collect()
+    }
+    /// Returns the version in the first `n` numeric parts, as `16.4` of `16.4.1`.
+    ///
+    /// The suffix is dropped if the parts are cut.
+    pub(crate) fn truncate(&self, n: usize) -> Self {
+        let parts = self.parts();
+        if parts.len() <= n {
+            return self.clone();
+        }
+        let mut nums = parts.into_iter().take(n).map(Some);
+        Self {
+            major: nums.next().flatten(),
+            minor: nums.next().flatten(),
+            patch: nums.next().flatten(),
+            patch_minor: nums.next().flatten(),
+            suffix: String::new(),
+        }
+    }
+}
+
+impl std::fmt::Display for Version {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let mut first = true;
+        for n in self.parts() {
+            if !first {
+                write!(f, ".")?;
+            }
+            write!(f, "{n}")?;
+            first = false;
+        }
+        write!(f, "{}", self.suffix)
+    }
+}
+
+// the dotted string is shorter and easier to read than the fields
+impl std::fmt::Debug for Version {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:?}", self.to_string())
+    }
+}
+
+impl std::str::FromStr for Version {
+    type Err = std::convert::Infallible;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::parse(s))
+    }
+}
+
+impl std::convert::From<&str> for Version {
+    fn from(val: &str) -> Self {
+        Self::parse(val)
+    }
+}
+
+impl Version {
+    // the key of the comparison, the missing parts are `0`.
+    // the empty version is the least, and a version without any number,
+    // as `K`, is less than `0`.
+    fn key(&self) -> (u8, [u32; 4]) {
+        let nums = [self.major, self.minor, self.patch, self.patch_minor];
+        let rank = match (self.major, self.suffix.is_empty()) {
+            (Some(_), _) => 2,
+            (None, false) => 1,
+            (None, true) => 0,
+        };
+        (rank, nums.map(|a| a.unwrap_or(0)))
+    }
+    // the numeric parts after the fourth one in `suffix`, without the trailing `0`,
+    // and the rest, as `[5]` and `b1` of `1.2.3.4.5.0b1`.
+    fn extra_parts(&self) -> (Vec<u32>, &str) {
+        let mut extra = Vec::new();
+        let mut rest = self.suffix.as_str();
+        if self.patch_minor.is_some() {
+            while let Some(body) = rest.strip_prefix('.') {
+                let len = body.bytes().take_while(|b| b.is_ascii_digit()).count();
+                let Some(n) = body[..len].parse::<u32>().ok() else {
+                    break;
+                };
+                extra.push(n);
+                rest = &body[len..];
+            }
+        }
+        while extra.last() == Some(&0) {
+            extra.pop();
+        }
+        (extra, rest)
+    }
+}
+
+impl Ord for Version {
+    /// The numeric parts are compared first, a missing part is `0`.
+    /// With the same numbers, a version that has a suffix is a pre-release,
+    /// so it is less than the one without. The empty version is the least.
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (extra, suffix) = self.extra_parts();
+        let (other_extra, other_suffix) = other.extra_parts();
+        self.key()
+            .cmp(&other.key())
+            .then_with(|| extra.cmp(&other_extra))
+            .then_with(|| match (suffix.is_empty(), other_suffix.is_empty()) {
+                (true, true) => Ordering::Equal,
+                (true, false) => Ordering::Greater,
+                (false, true) => Ordering::Less,
+                (false, false) => suffix.cmp(other_suffix),
+            })
+    }
+}
+
+// the same as `Ord`, so that `120` equals `120.0`
+impl PartialEq for Version {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Version {}
+
+impl std::hash::Hash for Version {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.key().hash(state);
+        self.extra_parts().hash(state);
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Serialize for Version {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Version {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Ok(Self::parse(&s))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_parse_01() {
+        let v = Version::parse("142.0.7444.102");
+        assert_eq!(v.major, Some(142));
+        assert_eq!(v.minor, Some(0));
+        assert_eq!(v.patch, Some(7444));
+        assert_eq!(v.patch_minor, Some(102));
+        assert_eq!(v.suffix, "");
+        assert_eq!(v.to_string(), "142.0.7444.102");
+    }
+    #[test]
+    fn test_parse_02() {
+        let v = Version::parse("3.0b3");
+        assert_eq!(v.parts(), vec![3, 0]);
+        assert_eq!(v.suffix, "b3");
+        assert_eq!(v.to_string(), "3.0b3");
+        //
+        let v = Version::parse("1.beta");
+        assert_eq!(v.parts(), vec![1]);
+        assert_eq!(v.suffix, ".beta");
+        assert_eq!(v.to_string(), "1.beta");
+        //
+        let v = Version::parse("K");
+        assert_eq!(v.parts(), Vec::<u32>::new());
+        assert_eq!(v.suffix, "K");
+        assert!(!v.is_empty());
+        //
+        let v = Version::parse("");
+        assert!(v.is_empty());
+        assert_eq!(v, Version::default());
+    }
+    #[test]
+    fn test_parse_extra_parts() {
+        let v = Version::parse("468.0.0.50.106");
+        assert_eq!(v.parts(), vec![468, 0, 0, 50]);
+        assert_eq!(v.suffix, ".106");
+        assert_eq!(v.to_string(), "468.0.0.50.106");
+    }
+    #[test]
+    fn test_from_parts() {
+        let v = Version::from_parts([Some("145"), Some("0"), None, Some("1")]);
+        assert_eq!(v.to_string(), "145.0");
+        let v = Version::from_parts([None, None, None, None]);
+        assert!(v.is_empty());
+    }
+    #[test]
+    fn test_ord() {
+        let v = |s: &str| Version::parse(s);
+        assert!(v("120.0.6099.230") >= v("120"));
+        assert!(v("119.9") < v("120"));
+        assert!(v("120") == v("120.0"));
+        assert!(v("120") < v("120.0.1"));
+        assert!(v("14") >= v("14.0.0"));
+        assert!(v("") < v("0"));
+        assert!(v("10.15.7") > v("10.9"));
+        assert!(v("3.0b3") < v("3.0"));
+        assert!(v("3.0a1") < v("3.0b3"));
+        assert!(v("") < v("1"));
+        // the fifth part is not a pre-release
+        assert!(v("1.2.3.4.5") > v("1.2.3.4"));
+        assert!(v("1.2.3.4.5") < v("1.2.3.4.10"));
+        assert!(v("1.2.3.4.0") == v("1.2.3.4"));
+        assert!(v("1.2.3.4.5b1") < v("1.2.3.4.5"));
+        assert!(v("1.2.3.4.5b1") > v("1.2.3.4.4"));
+        // the empty one is the least
+        assert!(v("") < v("K"));
+        assert!(v("K") < v("0"));
+        let mut list = vec![v("1"), v("K"), v("")];
+        list.sort();
+        assert_eq!(list, vec![v(""), v("K"), v("1")]);
+    }
+    #[test]
+    fn test_serde() {
+        let v = Version::parse("16.4.1");
+        let s = serde_json::to_string(&v).unwrap();
+        assert_eq!(s, r#""16.4.1""#);
+        let v2: Version = serde_json::from_str(&s).unwrap();
+        assert_eq!(v2, v);
+        assert_eq!(format!("{v:?}"), r#""16.4.1""#);
+    }
+}
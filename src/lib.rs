@@ -34,6 +34,177 @@ let browser = broinfo.to_browser();
 # Ok(())
 # }
 ```
+### custom regexes
+```rust
+use browserinfo::{BroInfo, UaParser};
+
+# fn func(broinfo: &BroInfo) -> anyhow::Result<()> {
+# #[cfg(feature = "yaml")]
+# {
+// Load a newer `regexes.yaml` of `uap-core` at runtime, with the `yaml` feature
+let parser = UaParser::builder()
+    .regexes_path("/path/to/uap-core/regexes.yaml")
+    .build()?;
+let browser = broinfo.to_browser_with(&parser)?;
+# }
+# Ok(())
+# }
+```
+### bots
+```rust
+use browserinfo::{BroInfo, ClientKind};
+
+# fn func(broinfo: &BroInfo) -> anyhow::Result<()> {
+let browser = broinfo.to_browser()?;
+// Count only the browsers, not crawlers, `curl` or `HeadlessChrome`
+if browser.client.kind == ClientKind::Browser {
+    // ...
+}
+# Ok(())
+# }
+```
+### preload
+```rust
+# fn func() -> anyhow::Result<()> {
+// Compile the bundled regexes at startup, instead of on the first request
+browserinfo::preload()?;
+# Ok(())
+# }
+```
+### cache
+```rust
+use browserinfo::UaParser;
+
+# fn func() -> anyhow::Result<()> {
+// Cache the results of the frequent `user agent` strings
+let parser = UaParser::builder().cache_size(4096).build()?;
+// Set it as the shared parser of `to_browser()`, before the first request
+let _ = browserinfo::init_with(parser);
+let stats = browserinfo::try_init()?.cache_stats();
+# Ok(())
+# }
+```
+### batch
+```rust
+# fn func(lines: &[String]) -> anyhow::Result<()> {
+// Parse the `user agent` strings of the access logs, in the input order.
+// With the `parallel` feature, the parsing is spread across threads.
+let browsers = browserinfo::parse_many(lines)?;
+# Ok(())
+# }
+```
+### device models
+```rust
+use browserinfo::UaParser;
+
+# fn func(ua: &str) -> anyhow::Result<()> {
+# #[cfg(feature = "yaml")]
+# {
+// The marketing names of the device models, as `Galaxy S23 Ultra` of `SM-S918B`,
+// and more of them in the same format as `resources/device_models.yaml`
+let parser = UaParser::builder()
+    .device_models_path("/path/to/device_models.yaml")
+    .build()?;
+let device = parser.parse(ua).device;
+println!("{} {}", device.manufacturer, device.marketing_name);
+# }
+# Ok(())
+# }
+```
+### requirement
+```rust
+use browserinfo::{BroInfo, BrowserRequirement};
+
+# fn func(broinfo: &BroInfo) -> anyhow::Result<()> {
+let browser = broinfo.to_browser()?;
+// Gate a feature by the browser, "Chrome" also covers "Chrome Mobile" with the aliases.
+// `browser.satisfies(..)` is the short form with the exact names only.
+let req = BrowserRequirement::parse("Chrome >= 110 || Firefox >= 115 || Safari >= 16.4")?;
+if req.with_aliases(true).matches(&browser) {
+    // ...
+}
+# Ok(())
+# }
+```
+### browserslist
+```rust
+use browserinfo::{BroInfo, Browserslist};
+
+# fn func(broinfo: &BroInfo) -> anyhow::Result<()> {
+// The same queries as the front-end build, with the embedded snapshot
+let modern = Browserslist::parse("defaults, not dead")?;
+if !modern.contains(&broinfo.to_browser()?) {
+    // load the fallback bundle
+}
+# Ok(())
+# }
+```
+### architecture
+```rust
+use browserinfo::{Architecture, BroInfo};
+
+# fn func(broinfo: &BroInfo) -> anyhow::Result<()> {
+let browser = broinfo.to_browser()?;
+// Pick the native download, with `platform`, `oscpu` and `Sec-CH-UA-Arch` if any
+let download = match browser.architecture {
+    Architecture::Arm64 => "app-arm64.zip",
+    Architecture::X86 => "app-x86.zip",
+    _ => "app-x86_64.zip",
+};
+# Ok(())
+# }
+```
+### runtime
+```rust
+use browserinfo::{BroInfo, Runtime};
+
+# fn func(broinfo: &BroInfo) -> anyhow::Result<()> {
+let browser = broinfo.to_browser()?;
+// Show the `open in browser` banner in an embedded web view, not in a browser tab
+let banner = browser.runtime.is_embedded();
+let native_bridge = matches!(browser.runtime, Runtime::Capacitor | Runtime::Electron);
+# Ok(())
+# }
+```
+### in-app browser
+```rust
+# use browserinfo::BroInfo;
+# fn func(broinfo: &BroInfo) -> anyhow::Result<()> {
+let browser = broinfo.to_browser()?;
+// Tell the user to open the page in the browser, before the `OAuth` popup
+if let Some(in_app) = &browser.in_app {
+    println!("opened in {} {}", in_app.name, in_app.version);
+}
+# Ok(())
+# }
+```
+### explain
+```rust
+# fn func(ua: &str) -> anyhow::Result<()> {
+# #[cfg(feature = "extractor")]
+# {
+// See which rules of `regexes.yaml` matched, and what `ua_parser` returned
+let explanation = browserinfo::explain(ua)?;
+println!("{explanation}");
+# }
+# Ok(())
+# }
+```
+### lite
+```rust
+use browserinfo::UaParser;
+
+# fn func() -> anyhow::Result<()> {
+# #[cfg(all(feature = "lite", feature = "extractor"))]
+# {
+// Find the major browsers by their tokens, with the `lite` feature,
+// and use the full parser only for the rest.
+// Without the `extractor` feature, only the lightweight parser is built.
+let parser = UaParser::builder().lite(true).build()?;
+# }
+# Ok(())
+# }
+```
 */
 
 mod li;
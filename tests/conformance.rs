@@ -0,0 +1,172 @@
+//
+// The conformance suites of `uap-core`, against the bundled `regexes.yaml`.
+//
+// The fixtures are in the `core` git submodule, and the suites are opted in:
+//   git submodule update --init core
+//   make test-conformance
+//
+// Without the submodule, the opted in suites fail.
+// The known divergences are listed in `tests/conformance_allow.txt`.
+//
+#![cfg(feature = "extractor")]
+
+use browserinfo::{Browser, UaParser, Version};
+use serde_yaml::Value;
+use std::collections::HashSet;
+use std::path::Path;
+
+const CORE_TESTS: &str = "core/tests";
+const ALLOW_LIST: &str = "tests/conformance_allow.txt";
+
+#[derive(Debug, Default)]
+struct Report {
+    passed: usize,
+    failed: Vec<String>,
+    allowed: usize,
+}
+
+// the expected values and the actual values of a case
+type Check = fn(&Value, &Browser) -> (String, String);
+
+#[test]
+#[ignore = "needs the `core` submodule, run `make test-conformance`"]
+fn test_conformance() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join(CORE_TESTS);
+    if !dir.is_dir() {
+        panic!(
+            "the fixtures of uap-core are not found at {dir:?}; \
+             run `git submodule update --init core`, or `make test-conformance`"
+        );
+    }
+    let allow = load_allow_list();
+    let parser = UaParser::new().unwrap();
+    let suites: [(&str, Check); 3] = [
+        ("test_ua.yaml", check_ua),
+        ("test_os.yaml", check_os),
+        ("test_device.yaml", check_device),
+    ];
+    let mut failed = Vec::new();
+    for (suite, check) in suites {
+        let report = run_suite(&parser, &dir.join(suite), suite, check, &allow);
+        println!(
+            "{suite}: passed {}, failed {}, allowed {}",
+            report.passed,
+            report.failed.len(),
+            report.allowed
+        );
+        failed.extend(report.failed);
+    }
+    assert!(
+        failed.is_empty(),
+        "{} cases failed:\n{}",
+        failed.len(),
+        failed.join("\n")
+    );
+}
+
+fn run_suite(
+    parser: &UaParser,
+    path: &Path,
+    suite: &str,
+    check: Check,
+    allow: &HashSet<(String, String)>,
+) -> Report {
+    let s = std::fs::read_to_string(path).unwrap_or_else(|e| panic!("{path:?}: {e}"));
+    let doc: Value = serde_yaml::from_str(&s).unwrap_or_else(|e| panic!("{path:?}: {e}"));
+    let cases = doc
+        .get("test_cases")
+        .and_then(|a| a.as_sequence())
+        .unwrap_or_else(|| panic!("{path:?}: no test_cases"));
+    let mut report = Report::default();
+    for case in cases {
+        let ua = field(case, "user_agent_string").unwrap_or_default();
+        let browser = parser.parse(&ua);
+        let (expected, actual) = check(case, &browser);
+        if expected == actual {
+            report.passed += 1;
+        } else if allow.contains(&(suite.to_string(), ua.clone())) {
+            report.allowed += 1;
+        } else {
+            report.failed.push(format!(
+                "{suite}\t{ua}\n    expected: {expected}\n    actual:   {actual}"
+            ));
+        }
+    }
+    report
+}
+
+fn check_ua(case: &Value, browser: &Browser) -> (String, String) {
+    let expected = format!(
+        "{} {}",
+        field(case, "family").unwrap_or_default(),
+        version(case)
+    );
+    let actual = format!("{} {}", or_other(&browser.name), browser.version);
+    (expected, actual)
+}
+
+fn check_os(case: &Value, browser: &Browser) -> (String, String) {
+    let expected = format!(
+        "{} {}",
+        field(case, "family").unwrap_or_default(),
+        version(case)
+    );
+    let os = browser.os.clone().unwrap_or_default();
+    let actual = format!("{} {}", or_other(&os.name), os.version);
+    (expected, actual)
+}
+
+fn check_device(case: &Value, browser: &Browser) -> (String, String) {
+    // `Browser::device` has ` Build/...` stripped
+    let expected = [
+        strip_build(field(case, "family").unwrap_or_default()),
+        field(case, "brand").unwrap_or_default(),
+        strip_build(field(case, "model").unwrap_or_default()),
+    ];
+    let device = &browser.device;
+    let actual = [or_other(&device.family), &device.brand, &device.model];
+    (expected.join(" / "), actual.join(" / "))
+}
+
+// `uap-core` says `Other` for no match, and `Browser` has the empty name.
+fn or_other(name: &str) -> &str {
+    if name.is_empty() {
+        "Other"
+    } else {
+        name
+    }
+}
+
+fn version(case: &Value) -> Version {
+    let parts: Vec<String> = ["major", "minor", "patch", "patch_minor"]
+        .into_iter()
+        .map_while(|a| field(case, a).filter(|a| !a.is_empty()))
+        .collect();
+    Version::parse(&parts.join("."))
+}
+
+fn strip_build(s: String) -> String {
+    match s.rfind(" Build/") {
+        Some(idx) => s[..idx].to_string(),
+        None => s,
+    }
+}
+
+fn field(case: &Value, key: &str) -> Option<String> {
+    match case.get(key)? {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+// `suite<TAB>user_agent_string` per line, and `#` is a comment
+fn load_allow_list() -> HashSet<(String, String)> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(ALLOW_LIST);
+    let s = std::fs::read_to_string(&path).unwrap_or_default();
+    s.lines()
+        .filter(|a| !a.trim().is_empty() && !a.starts_with('#'))
+        .filter_map(|a| a.split_once('\t'))
+        .map(|(suite, ua)| (suite.to_string(), ua.to_string()))
+        .collect()
+}